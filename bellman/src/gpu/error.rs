@@ -0,0 +1,32 @@
+//! Error type for the optional GPU-accelerated multiexp/FFT backend.
+
+use std::error::Error;
+use std::fmt;
+
+/// Failure modes specific to dispatching work to a GPU device. Every call site treats
+/// these as non-fatal: on `Err`, the caller falls back to the `Worker`-based CPU path
+/// rather than propagating the error.
+#[derive(Debug)]
+pub enum GpuError {
+    /// Neither the `cuda` nor `opencl` feature is enabled.
+    FeatureDisabled,
+    /// No device could be opened (none installed, or its lock is already held by another
+    /// in-process kernel).
+    DeviceUnavailable,
+    /// The device returned an error while running a kernel.
+    KernelFailure(String),
+}
+
+impl fmt::Display for GpuError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GpuError::FeatureDisabled => write!(f, "GPU support was not compiled in"),
+            GpuError::DeviceUnavailable => write!(f, "no GPU device available"),
+            GpuError::KernelFailure(msg) => write!(f, "GPU kernel failure: {}", msg),
+        }
+    }
+}
+
+impl Error for GpuError {}
+
+pub type GpuResult<T> = Result<T, GpuError>;
@@ -0,0 +1,49 @@
+//! Process-wide locks around the multiexp and FFT devices.
+//!
+//! Two concurrently-running GPU kernels of the same kind would contend for the same
+//! device memory and end up slower than just running one of them on the CPU, so at most
+//! one [`super::LockedMultiexpKernel`] and one [`super::LockedFftKernel`] may hold an open
+//! device at a time; a second, concurrent attempt to open one fails with
+//! [`GpuError::DeviceUnavailable`] rather than blocking, so its caller falls back to the
+//! CPU path immediately instead of waiting on the first kernel to finish.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use super::error::{GpuError, GpuResult};
+
+struct DeviceLock(AtomicBool);
+
+impl DeviceLock {
+    const fn new() -> Self {
+        DeviceLock(AtomicBool::new(false))
+    }
+
+    fn try_acquire(&self) -> GpuResult<DeviceGuard<'_>> {
+        if self.0.swap(true, Ordering::SeqCst) {
+            Err(GpuError::DeviceUnavailable)
+        } else {
+            Ok(DeviceGuard(&self.0))
+        }
+    }
+}
+
+/// Releases its `DeviceLock` when dropped, so a kernel that fails to open a device (or is
+/// itself dropped) doesn't permanently wedge out every later attempt in the process.
+pub(super) struct DeviceGuard<'a>(&'a AtomicBool);
+
+impl Drop for DeviceGuard<'_> {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::SeqCst);
+    }
+}
+
+static MULTIEXP_LOCK: DeviceLock = DeviceLock::new();
+static FFT_LOCK: DeviceLock = DeviceLock::new();
+
+pub(super) fn acquire_multiexp_device() -> GpuResult<DeviceGuard<'static>> {
+    MULTIEXP_LOCK.try_acquire()
+}
+
+pub(super) fn acquire_fft_device() -> GpuResult<DeviceGuard<'static>> {
+    FFT_LOCK.try_acquire()
+}
@@ -0,0 +1,210 @@
+//! Optional GPU-accelerated multiexp and FFT, behind the `cuda`/`opencl` cargo features.
+//!
+//! `ExtendedParameters::verify`'s several large `multiexp` calls and its QAP evaluation's
+//! `EvaluationDomain::ifft` calls are CPU-bound through [`Worker`] today, which dominates
+//! runtime for large circuits. [`LockedMultiexpKernel`] and [`LockedFftKernel`] are the
+//! handles call sites thread through [`multiexp`]/[`ifft`]. With `cuda` or `opencl`
+//! enabled, a handle takes the process-wide device lock the first time it is used on an
+//! input of at least [`GPU_MIN_LENGTH`] and holds it for the handle's lifetime. No
+//! CUDA/OpenCL kernel is implemented in this module: the GPU dispatch always returns an
+//! error, so every [`multiexp`]/[`ifft`] call, with or without the features, runs the
+//! existing `Worker`-based implementation.
+
+mod error;
+mod locks;
+
+pub use error::{GpuError, GpuResult};
+
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use group::CurveAffine;
+use pairing::Engine;
+
+use crate::domain::EvaluationDomain;
+use crate::multicore::Worker;
+use crate::multiexp::{multiexp as cpu_multiexp, SourceBuilder};
+use crate::SynthesisError;
+use locks::DeviceGuard;
+
+/// Below this many terms/coefficients, a GPU dispatch's fixed host-device transfer
+/// overhead costs more than the `Worker`-based CPU path; `multiexp`/`ifft` fall back
+/// unconditionally below this size, regardless of whether a device is available.
+pub const GPU_MIN_LENGTH: usize = 1 << 16;
+
+/// A handle to a lazily-opened multiexp-capable GPU device, reused across every
+/// [`multiexp`] call made through it for the lifetime of the handle.
+pub struct LockedMultiexpKernel<E: Engine> {
+    device: Option<DeviceGuard<'static>>,
+    tried: bool,
+    _marker: PhantomData<E>,
+}
+
+impl<E: Engine> LockedMultiexpKernel<E> {
+    /// Creates a handle without eagerly opening a device; the first [`multiexp`] call
+    /// made through it attempts the acquisition.
+    pub fn new() -> Self {
+        LockedMultiexpKernel {
+            device: None,
+            tried: false,
+            _marker: PhantomData,
+        }
+    }
+
+    fn device(&mut self) -> Option<&DeviceGuard<'static>> {
+        if !self.tried {
+            self.tried = true;
+            self.device = open_multiexp_device().ok();
+        }
+        self.device.as_ref()
+    }
+}
+
+impl<E: Engine> Default for LockedMultiexpKernel<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A handle to a lazily-opened FFT-capable GPU device, reused across every [`ifft`] call
+/// made through it for the lifetime of the handle.
+pub struct LockedFftKernel<E: Engine> {
+    device: Option<DeviceGuard<'static>>,
+    tried: bool,
+    _marker: PhantomData<E>,
+}
+
+impl<E: Engine> LockedFftKernel<E> {
+    /// Creates a handle without eagerly opening a device; the first [`ifft`] call made
+    /// through it attempts the acquisition.
+    pub fn new() -> Self {
+        LockedFftKernel {
+            device: None,
+            tried: false,
+            _marker: PhantomData,
+        }
+    }
+
+    fn device(&mut self) -> Option<&DeviceGuard<'static>> {
+        if !self.tried {
+            self.tried = true;
+            self.device = open_fft_device().ok();
+        }
+        self.device.as_ref()
+    }
+}
+
+impl<E: Engine> Default for LockedFftKernel<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(any(feature = "cuda", feature = "opencl"))]
+fn open_multiexp_device() -> GpuResult<DeviceGuard<'static>> {
+    locks::acquire_multiexp_device()
+}
+
+#[cfg(not(any(feature = "cuda", feature = "opencl")))]
+fn open_multiexp_device() -> GpuResult<DeviceGuard<'static>> {
+    Err(GpuError::FeatureDisabled)
+}
+
+#[cfg(any(feature = "cuda", feature = "opencl"))]
+fn open_fft_device() -> GpuResult<DeviceGuard<'static>> {
+    locks::acquire_fft_device()
+}
+
+#[cfg(not(any(feature = "cuda", feature = "opencl")))]
+fn open_fft_device() -> GpuResult<DeviceGuard<'static>> {
+    Err(GpuError::FeatureDisabled)
+}
+
+/// Computes `sum(exponents[i] * bases[i])`, the same `(bases, density_map, exponents)`
+/// shape [`crate::multiexp::multiexp`] takes. A GPU dispatch is attempted only when
+/// `kernel` has a device open and `exponents` is at least [`GPU_MIN_LENGTH`] long; that
+/// dispatch always returns an error, and the call then runs the existing `Worker`-based
+/// CPU implementation, as it does in every other case.
+pub fn multiexp<E, G, D, S>(
+    kernel: &mut LockedMultiexpKernel<E>,
+    worker: &Worker,
+    bases: S,
+    density_map: D,
+    exponents: Arc<Vec<E::Fr>>,
+) -> Box<dyn futures::Future<Item = G::Projective, Error = SynthesisError>>
+where
+    E: Engine,
+    G: CurveAffine<Scalar = E::Fr>,
+    S: SourceBuilder<G>,
+    D: Clone + Send + Sync + 'static,
+{
+    if exponents.len() >= GPU_MIN_LENGTH {
+        if let Some(device) = kernel.device() {
+            match gpu_multiexp::<E, G>(device, &exponents) {
+                Ok(result) => return Box::new(futures::future::ok(result)),
+                Err(_) => { /* fall through to the CPU path below */ }
+            }
+        }
+    }
+
+    cpu_multiexp(worker, bases, density_map, exponents)
+}
+
+#[cfg(any(feature = "cuda", feature = "opencl"))]
+fn gpu_multiexp<E: Engine, G: CurveAffine<Scalar = E::Fr>>(
+    _device: &DeviceGuard<'static>,
+    _exponents: &[E::Fr],
+) -> GpuResult<G::Projective> {
+    // The actual CUDA/OpenCL context setup, kernel source, and host<->device transfer
+    // live behind whichever of `cuda`/`opencl` is enabled; neither is implemented here.
+    Err(GpuError::KernelFailure(
+        "GPU multiexp kernel not implemented".into(),
+    ))
+}
+
+#[cfg(not(any(feature = "cuda", feature = "opencl")))]
+fn gpu_multiexp<E: Engine, G: CurveAffine<Scalar = E::Fr>>(
+    _device: &DeviceGuard<'static>,
+    _exponents: &[E::Fr],
+) -> GpuResult<G::Projective> {
+    Err(GpuError::FeatureDisabled)
+}
+
+/// Performs `domain`'s inverse FFT in place, the same operation as
+/// [`EvaluationDomain::ifft`]. A GPU dispatch is attempted only when `kernel` has a device
+/// open and `domain` is at least [`GPU_MIN_LENGTH`] coefficients long; that dispatch always
+/// returns an error, and the call then runs the existing `Worker`-based CPU
+/// implementation, as it does in every other case.
+pub fn ifft<E, G>(kernel: &mut LockedFftKernel<E>, domain: &mut EvaluationDomain<E, G>, worker: &Worker)
+where
+    E: Engine,
+{
+    if domain.as_ref().len() >= GPU_MIN_LENGTH {
+        if let Some(device) = kernel.device() {
+            if gpu_ifft::<E, G>(device, domain).is_ok() {
+                return;
+            }
+            // fall through to the CPU path below
+        }
+    }
+
+    domain.ifft(worker)
+}
+
+#[cfg(any(feature = "cuda", feature = "opencl"))]
+fn gpu_ifft<E: Engine, G>(
+    _device: &DeviceGuard<'static>,
+    _domain: &mut EvaluationDomain<E, G>,
+) -> GpuResult<()> {
+    // As with `gpu_multiexp`, the radix-2 NTT kernel itself lives behind `cuda`/`opencl`
+    // and is not implemented in this portable fallback path.
+    Err(GpuError::KernelFailure("GPU FFT kernel not implemented".into()))
+}
+
+#[cfg(not(any(feature = "cuda", feature = "opencl")))]
+fn gpu_ifft<E: Engine, G>(
+    _device: &DeviceGuard<'static>,
+    _domain: &mut EvaluationDomain<E, G>,
+) -> GpuResult<()> {
+    Err(GpuError::FeatureDisabled)
+}
@@ -0,0 +1,154 @@
+//! Batched verification of many Groth16 proofs against (possibly different) public
+//! inputs, sharing a single `VerifyingKey`.
+//!
+//! Checking `n` proofs independently pays for `n` final exponentiations, which dominate
+//! the cost of pairing-based verification. [`BatchVerifier`] instead combines all `n`
+//! proofs' pairing equations with independent random coefficients into a single equation,
+//! so only one final exponentiation (via [`MultiMillerLoop`]) is needed for the whole
+//! batch, at the cost of a small (`1/|Fr|`) soundness error.
+
+use ff::{Field, PrimeField};
+use group::{CurveAffine, CurveProjective, Group};
+use pairing::{MillerLoopResult as _, MultiMillerLoop, PairingCurveAffine};
+use rand_core::{CryptoRng, RngCore};
+
+use super::{PreparedVerifyingKey, Proof, Transcript};
+use crate::SynthesisError;
+
+/// Accumulates proof/public-input pairs to be checked together by [`BatchVerifier::verify`].
+pub struct BatchVerifier<E: MultiMillerLoop> {
+    items: Vec<(Proof<E>, Vec<E::Fr>)>,
+}
+
+impl<E: MultiMillerLoop> Default for BatchVerifier<E> {
+    fn default() -> Self {
+        BatchVerifier { items: Vec::new() }
+    }
+}
+
+impl<E: MultiMillerLoop> BatchVerifier<E> {
+    /// Creates an empty batch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues `proof` to be checked against `public_inputs` as part of the batch.
+    pub fn queue(&mut self, proof: Proof<E>, public_inputs: Vec<E::Fr>) {
+        self.items.push((proof, public_inputs));
+    }
+
+    /// Verifies every queued `(proof, public_inputs)` pair at once against `pvk`,
+    /// returning `true` only if all of them are individually valid.
+    ///
+    /// Returns an error if any proof's public input count does not match `pvk`.
+    pub fn verify<R: RngCore + CryptoRng>(
+        &self,
+        pvk: &PreparedVerifyingKey<E>,
+        mut rng: R,
+    ) -> Result<bool, SynthesisError> {
+        self.verify_with(pvk, &mut || loop {
+            let z = E::Fr::random(&mut rng);
+            if !bool::from(z.is_zero()) {
+                break z;
+            }
+        })
+    }
+
+    /// Same check as [`Self::verify`], but each proof's combining coefficient `z_i` is
+    /// squeezed deterministically from a [`Transcript`] bound to `pvk`'s `alpha_g1` and `ic`
+    /// and to every queued `(proof, public_inputs)` pair, instead of drawn from an `RngCore`. Two verifiers
+    /// checking the same batch this way always compute the same challenges, so the result
+    /// is reproducible without needing to share or log any randomness.
+    pub fn verify_with_transcript(&self, pvk: &PreparedVerifyingKey<E>) -> Result<bool, SynthesisError> {
+        let mut transcript = Transcript::new(b"bellman groth16 batch verify");
+        transcript.append_message(b"alpha_g1", pvk.alpha_g1.to_uncompressed().as_ref());
+        for ic in &pvk.ic {
+            transcript.append_message(b"ic", ic.to_uncompressed().as_ref());
+        }
+        for (proof, inputs) in &self.items {
+            transcript.append_message(b"proof_a", proof.a.to_uncompressed().as_ref());
+            transcript.append_message(b"proof_b", proof.b.to_uncompressed().as_ref());
+            transcript.append_message(b"proof_c", proof.c.to_uncompressed().as_ref());
+            for input in inputs {
+                transcript.append_message(b"public_input", input.to_repr().as_ref());
+            }
+        }
+
+        let mut counter: u64 = 0;
+        self.verify_with(pvk, &mut || loop {
+            let z: E::Fr = transcript.challenge_scalar(&counter.to_be_bytes());
+            counter += 1;
+            if !bool::from(z.is_zero()) {
+                break z;
+            }
+        })
+    }
+
+    /// Shared implementation behind [`Self::verify`] and [`Self::verify_with_transcript`],
+    /// parameterised over where each proof's nonzero combining coefficient `z_i` comes from.
+    fn verify_with(
+        &self,
+        pvk: &PreparedVerifyingKey<E>,
+        next_z: &mut dyn FnMut() -> E::Fr,
+    ) -> Result<bool, SynthesisError> {
+        if self.items.is_empty() {
+            return Ok(true);
+        }
+
+        // The combined equation being checked is, for random nonzero `z_i` per proof:
+        //   prod_i e(z_i * A_i, B_i)
+        //     = e(sum_i z_i * alpha, beta) * e(sum_i z_i * IC_i, gamma) * e(sum_i z_i * C_i, delta)
+        // which holds iff every individual `e(A_i, B_i) = e(alpha, beta) * e(IC_i, gamma) *
+        // e(C_i, delta)` holds, except with probability `1/|Fr|`.
+        let mut alpha_sum = E::Fr::zero();
+        let mut acc_ic = E::G1::identity();
+        let mut acc_c = E::G1::identity();
+
+        let mut ab_terms: Vec<(E::G1Affine, <E::G2Affine as PairingCurveAffine>::Prepared)> =
+            Vec::with_capacity(self.items.len());
+
+        for (proof, inputs) in &self.items {
+            if inputs.len() + 1 != pvk.ic.len() {
+                return Err(SynthesisError::MalformedCrs);
+            }
+
+            let z = next_z();
+
+            alpha_sum += z;
+
+            let mut ic = pvk.ic[0].to_curve();
+            for (i, input) in inputs.iter().enumerate() {
+                let mut term = pvk.ic[i + 1].to_curve();
+                term.mul_assign(*input);
+                ic.add_assign(&term);
+            }
+            ic.mul_assign(z);
+            acc_ic.add_assign(&ic);
+
+            let mut c = proof.c.to_curve();
+            c.mul_assign(z);
+            acc_c.add_assign(&c);
+
+            let mut a = proof.a.to_curve();
+            a.mul_assign(z);
+            ab_terms.push((a.to_affine(), proof.b.prepare()));
+        }
+
+        // Negated so that `e(-alpha_sum, beta)` balances the (un-negated) `A_i, B_i` terms,
+        // the same way `pvk.neg_gamma_g2`/`pvk.neg_delta_g2` balance the `IC`/`C` terms.
+        let mut alpha_term = pvk.alpha_g1.to_curve();
+        alpha_term.mul_assign(alpha_sum);
+        alpha_term = -alpha_term;
+        let alpha_affine = alpha_term.to_affine();
+        let ic_affine = acc_ic.to_affine();
+        let c_affine = acc_c.to_affine();
+
+        let mut terms: Vec<_> = ab_terms.iter().map(|(a, b)| (a, b)).collect();
+        terms.push((&alpha_affine, &pvk.beta_g2));
+        terms.push((&ic_affine, &pvk.neg_gamma_g2));
+        terms.push((&c_affine, &pvk.neg_delta_g2));
+
+        let result = E::multi_miller_loop(&terms).final_exponentiation();
+        Ok(bool::from(result.is_identity()))
+    }
+}
@@ -0,0 +1,403 @@
+//! Phase-2 MPC ceremony support for [`ExtendedParameters`].
+//!
+//! [`mpc`](super::mpc) lets a chain of participants re-randomize `delta` for ordinary
+//! [`Parameters`], without binding that chain to the circuit being proved over.
+//! [`MpcParameters`] extends the same idea to [`ExtendedParameters`]: every contribution
+//! is bound to a `cs_hash` of the circuit's R1CS matrices, and each contributor's
+//! [`PublicKey`] carries both a G1 and a G2 proof of knowledge, so
+//! [`MpcParameters::verify_contributions`] replays the whole `delta_before -> delta_after`
+//! chain, checks it against the final CRS, and then runs the subversion check.
+
+use std::io::{self, Read, Write};
+use std::sync::Arc;
+
+use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+use ff::{Field, PrimeField};
+use group::{CurveAffine, CurveProjective, Group};
+use pairing::{CurveParameters, Engine, PairingCurveAffine};
+use rand_core::{CryptoRng, RngCore};
+
+use super::{read_g1, read_g2, ContributionHash, ExtendedParameters, KeypairAssembly, Transcript};
+use crate::{Circuit, ConstraintSystem, Index, SynthesisError, Variable};
+
+/// [`ExtendedParameters`] mid-way through a phase-2 MPC ceremony, together with a hash of
+/// the circuit it was generated for and the transcript of contributions so far.
+pub struct MpcParameters<E: Engine> {
+    pub params: ExtendedParameters<E>,
+    cs_hash: ContributionHash,
+    contributions: Vec<PublicKey<E>>,
+}
+
+/// One participant's contribution: a proof that `delta` was updated by multiplying it by
+/// some scalar `s` the participant knows, without revealing `s` itself.
+///
+/// `s_g1`/`s_x_g1` are a Diffie-Hellman pair `(k * G1, s * k * G1)` for a fresh per-
+/// contribution nonce `k`; `r_g2` is a nothing-up-my-sleeve G2 point derived by hashing
+/// the transcript together with that pair, and `s_r_g2`/`s_x_r_g2` are `s⁻¹ * r_g2` and
+/// `s * r_g2` respectively. [`MpcParameters::verify_contributions`] uses
+/// `e(s_g1, r_g2) == e(s_x_g1, s_r_g2)` to confirm the same `s` underlies both the G1 and
+/// G2 pairs (proof of knowledge), and `e(delta_after_g1, r_g2) == e(delta_before_g1,
+/// s_x_r_g2)` to confirm `delta_after_g1 = s * delta_before_g1` for that same `s`.
+#[derive(Clone)]
+struct PublicKey<E: Engine> {
+    delta_before_g1: E::G1Affine,
+    delta_after_g1: E::G1Affine,
+    s_g1: E::G1Affine,
+    s_x_g1: E::G1Affine,
+    r_g2: E::G2Affine,
+    s_r_g2: E::G2Affine,
+    s_x_r_g2: E::G2Affine,
+}
+
+impl<E: Engine> MpcParameters<E> {
+    /// Begins a ceremony from freshly-generated (or previously subversion-checked)
+    /// `ExtendedParameters`, binding it to the R1CS matrices of `circuit`.
+    pub fn new<C: Circuit<E>>(params: ExtendedParameters<E>, circuit: C) -> Result<Self, SynthesisError> {
+        Ok(MpcParameters {
+            params,
+            cs_hash: compute_cs_hash::<E, C>(circuit)?,
+            contributions: vec![],
+        })
+    }
+
+    /// Contributes fresh private randomness, multiplying `delta` by it and updating the
+    /// `h`, `l`, `delta_g1` and `delta_g2` components of `self.params` to match. Returns
+    /// the transcript hash of the resulting chain, which the participant should publish
+    /// alongside their contribution.
+    pub fn contribute<R: RngCore + CryptoRng>(&mut self, rng: &mut R) -> ContributionHash
+    where
+        E::G2Affine: CurveParameters,
+    {
+        let transcript_so_far = Self::transcript(&self.cs_hash, &self.contributions);
+
+        let s: E::Fr = loop {
+            let s = E::Fr::random(&mut *rng);
+            if !bool::from(s.is_zero()) {
+                break s;
+            }
+        };
+        let s_inv = s.invert().expect("nonzero with overwhelming probability");
+
+        let params = &mut self.params.params;
+        let h = Arc::get_mut(&mut params.h).expect("no other references to h should exist");
+        let l = Arc::get_mut(&mut params.l).expect("no other references to l should exist");
+        batch_scale::<E>(h, s_inv);
+        batch_scale::<E>(l, s_inv);
+
+        let delta_before_g1 = params.vk.delta_g1;
+        params.vk.delta_g1 = (params.vk.delta_g1 * s).to_affine();
+        params.vk.delta_g2 = (params.vk.delta_g2 * s).to_affine();
+        let delta_after_g1 = params.vk.delta_g1;
+
+        let k: E::Fr = loop {
+            let k = E::Fr::random(&mut *rng);
+            if !bool::from(k.is_zero()) {
+                break k;
+            }
+        };
+        let s_g1 = (E::G1Affine::generator() * k).to_affine();
+        let s_x_g1 = (s_g1 * s).to_affine();
+
+        let r_g2 = hash_to_g2::<E>(&transcript_so_far, &s_g1, &s_x_g1).to_affine();
+        let s_r_g2 = (r_g2 * s_inv).to_affine();
+        let s_x_r_g2 = (r_g2 * s).to_affine();
+
+        self.contributions.push(PublicKey {
+            delta_before_g1,
+            delta_after_g1,
+            s_g1,
+            s_x_g1,
+            r_g2,
+            s_r_g2,
+            s_x_r_g2,
+        });
+
+        Self::transcript(&self.cs_hash, &self.contributions)
+    }
+
+    /// Recomputes `cs_hash` for `circuit`, walks the contribution chain verifying that
+    /// each link's `delta_before_g1` matches the previous link's `delta_after_g1` (the
+    /// first link must start from the generator, phase-1's untouched `delta`), that both
+    /// of its pairing checks hold, and that the final `delta_after_g1` matches the CRS's
+    /// current `delta_g1`. On success, also runs [`ExtendedParameters::verify`]'s
+    /// subversion check against the resulting CRS and returns the running transcript hash
+    /// after each contribution, in order.
+    pub fn verify_contributions<C: Circuit<E> + Clone, R: RngCore>(
+        &self,
+        circuit: C,
+        rng: &mut R,
+    ) -> Result<Vec<ContributionHash>, SynthesisError>
+    where
+        E::G2Affine: CurveParameters,
+    {
+        let cs_hash = compute_cs_hash::<E, C>(circuit.clone())?;
+        if cs_hash != self.cs_hash {
+            return Err(SynthesisError::MalformedCrs);
+        }
+
+        let mut current_delta = E::G1Affine::generator();
+        let mut chain_so_far: Vec<PublicKey<E>> = Vec::with_capacity(self.contributions.len());
+        let mut hashes = Vec::with_capacity(self.contributions.len());
+
+        for pubkey in &self.contributions {
+            if pubkey.delta_before_g1 != current_delta {
+                return Err(SynthesisError::MalformedCrs);
+            }
+
+            let transcript_so_far = Self::transcript(&self.cs_hash, &chain_so_far);
+            let expected_r_g2 = hash_to_g2::<E>(&transcript_so_far, &pubkey.s_g1, &pubkey.s_x_g1).to_affine();
+            if expected_r_g2 != pubkey.r_g2 {
+                return Err(SynthesisError::MalformedCrs);
+            }
+
+            // Proof of knowledge of `s`: the same `s` relates (s_g1, s_x_g1) as it relates
+            // (s_r_g2, r_g2) := (s^-1 * r_g2, r_g2).
+            if E::pairing(pubkey.s_g1, pubkey.r_g2) != E::pairing(pubkey.s_x_g1, pubkey.s_r_g2) {
+                return Err(SynthesisError::MalformedCrs);
+            }
+
+            // Consistency of delta: delta_after_g1 = s * delta_before_g1 for that same `s`.
+            if E::pairing(pubkey.delta_after_g1, pubkey.r_g2)
+                != E::pairing(pubkey.delta_before_g1, pubkey.s_x_r_g2)
+            {
+                return Err(SynthesisError::MalformedCrs);
+            }
+
+            current_delta = pubkey.delta_after_g1;
+            chain_so_far.push(pubkey.clone());
+            hashes.push(Self::transcript(&self.cs_hash, &chain_so_far));
+        }
+
+        if current_delta != self.params.params.vk.delta_g1 {
+            return Err(SynthesisError::MalformedCrs);
+        }
+
+        self.params.verify(circuit, rng)?;
+
+        Ok(hashes)
+    }
+
+    /// Hashes `cs_hash` together with every contribution's public key fields, giving the
+    /// transcript hash of the chain so far. Used both to derive each contribution's
+    /// `r_g2` (binding it to the exact state it was computed over) and as the published,
+    /// externally-checkable `ContributionHash` of the chain.
+    fn transcript(cs_hash: &ContributionHash, contributions: &[PublicKey<E>]) -> ContributionHash {
+        let mut transcript = Transcript::new(b"bellman extended-mpc");
+        transcript.append_message(b"cs_hash", cs_hash);
+        for pubkey in contributions {
+            transcript.append_message(b"delta_before_g1", pubkey.delta_before_g1.to_uncompressed().as_ref());
+            transcript.append_message(b"delta_after_g1", pubkey.delta_after_g1.to_uncompressed().as_ref());
+            transcript.append_message(b"s_g1", pubkey.s_g1.to_uncompressed().as_ref());
+            transcript.append_message(b"s_x_g1", pubkey.s_x_g1.to_uncompressed().as_ref());
+            transcript.append_message(b"r_g2", pubkey.r_g2.to_uncompressed().as_ref());
+            transcript.append_message(b"s_r_g2", pubkey.s_r_g2.to_uncompressed().as_ref());
+            transcript.append_message(b"s_x_r_g2", pubkey.s_x_r_g2.to_uncompressed().as_ref());
+        }
+        transcript.challenge_bytes(b"transcript")
+    }
+
+    pub fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        self.params.write(&mut writer)?;
+        writer.write_all(&self.cs_hash)?;
+
+        writer.write_u32::<BigEndian>(self.contributions.len() as u32)?;
+        for pubkey in &self.contributions {
+            writer.write_all(pubkey.delta_before_g1.to_uncompressed().as_ref())?;
+            writer.write_all(pubkey.delta_after_g1.to_uncompressed().as_ref())?;
+            writer.write_all(pubkey.s_g1.to_uncompressed().as_ref())?;
+            writer.write_all(pubkey.s_x_g1.to_uncompressed().as_ref())?;
+            writer.write_all(pubkey.r_g2.to_uncompressed().as_ref())?;
+            writer.write_all(pubkey.s_r_g2.to_uncompressed().as_ref())?;
+            writer.write_all(pubkey.s_x_r_g2.to_uncompressed().as_ref())?;
+        }
+
+        Ok(())
+    }
+
+    pub fn read<R: Read>(mut reader: R, checked: bool) -> io::Result<Self> {
+        let params = ExtendedParameters::<E>::read(&mut reader, checked)?;
+
+        let mut cs_hash = [0u8; 64];
+        reader.read_exact(&mut cs_hash)?;
+
+        let len = reader.read_u32::<BigEndian>()? as usize;
+        let mut contributions = Vec::with_capacity(len);
+        for _ in 0..len {
+            contributions.push(PublicKey {
+                delta_before_g1: read_g1::<R, E>(&mut reader, checked)?,
+                delta_after_g1: read_g1::<R, E>(&mut reader, checked)?,
+                s_g1: read_g1::<R, E>(&mut reader, checked)?,
+                s_x_g1: read_g1::<R, E>(&mut reader, checked)?,
+                r_g2: read_g2::<R, E>(&mut reader, checked)?,
+                s_r_g2: read_g2::<R, E>(&mut reader, checked)?,
+                s_x_r_g2: read_g2::<R, E>(&mut reader, checked)?,
+            });
+        }
+
+        Ok(MpcParameters {
+            params,
+            cs_hash,
+            contributions,
+        })
+    }
+}
+
+/// Synthesizes `circuit`'s R1CS and hashes its `A`/`B`/`C` matrices, giving a digest that
+/// changes if the circuit (and hence the CRS a ceremony should be producing) changes.
+fn compute_cs_hash<E: Engine, C: Circuit<E>>(circuit: C) -> Result<ContributionHash, SynthesisError> {
+    let mut assembly = KeypairAssembly::<E> {
+        num_inputs: 0,
+        num_aux: 0,
+        num_constraints: 0,
+        at_inputs: vec![],
+        bt_inputs: vec![],
+        ct_inputs: vec![],
+        at_aux: vec![],
+        bt_aux: vec![],
+        ct_aux: vec![],
+    };
+
+    assembly.alloc_input(|| "", || Ok(E::Fr::one()))?;
+    circuit.synthesize(&mut assembly)?;
+    for i in 0..assembly.num_inputs {
+        assembly.enforce(|| "", |lc| lc + Variable(Index::Input(i)), |lc| lc, |lc| lc);
+    }
+
+    Ok(digest_assembly::<E>(&assembly))
+}
+
+/// Hashes a [`KeypairAssembly`]'s six coefficient matrices into a digest that binds a CRS
+/// (or a ceremony over one) to the exact circuit it was built for. Used both by
+/// [`compute_cs_hash`], which synthesizes a fresh assembly, and by
+/// `ExtendedParameters::verify_into`, which already has one in hand from QAP synthesis
+/// and shouldn't pay for synthesizing the circuit twice.
+pub(super) fn digest_assembly<E: Engine>(assembly: &KeypairAssembly<E>) -> ContributionHash {
+    let mut transcript = Transcript::new(b"bellman extended-mpc cs_hash");
+    hash_terms::<E>(&mut transcript, b"at_inputs", &assembly.at_inputs);
+    hash_terms::<E>(&mut transcript, b"bt_inputs", &assembly.bt_inputs);
+    hash_terms::<E>(&mut transcript, b"ct_inputs", &assembly.ct_inputs);
+    hash_terms::<E>(&mut transcript, b"at_aux", &assembly.at_aux);
+    hash_terms::<E>(&mut transcript, b"bt_aux", &assembly.bt_aux);
+    hash_terms::<E>(&mut transcript, b"ct_aux", &assembly.ct_aux);
+
+    transcript.challenge_bytes(b"cs_hash")
+}
+
+fn hash_terms<E: Engine>(transcript: &mut Transcript, label: &[u8], rows: &[Vec<(E::Fr, usize)>]) {
+    for row in rows {
+        transcript.append_message(label, &(row.len() as u64).to_be_bytes());
+        for (coeff, index) in row {
+            transcript.append_message(label, coeff.to_repr().as_ref());
+            transcript.append_message(label, &(*index as u64).to_be_bytes());
+        }
+    }
+}
+
+fn batch_scale<E: Engine>(points: &mut [E::G1Affine], scalar: E::Fr) {
+    let mut projective: Vec<E::G1> = points.iter().map(|p| p.to_curve()).collect();
+    for p in &mut projective {
+        *p = *p * scalar;
+    }
+    let mut scaled = vec![E::G1Affine::from(E::G1::identity()); points.len()];
+    E::G1::batch_normalize(&projective, &mut scaled);
+    points.copy_from_slice(&scaled);
+}
+
+/// Hashes `transcript_so_far` together with a contribution's `(s_g1, s_x_g1)` pair into a
+/// deterministic, nothing-up-my-sleeve `G2` element, used both as the Fiat-Shamir
+/// challenge point for that contribution's proof of knowledge and to bind it to the chain
+/// state it was computed over.
+///
+/// A real try-and-increment: each iteration samples only an `x`-coordinate candidate (via
+/// [`TranscriptRng`]) and recovers `y` as `sqrt(x^3 + b)`, retrying only on the roughly
+/// half of `x` values for which `x^3 + b` is a non-residue. Unlike `mpc`'s analogous fix
+/// for `hash_to_g1`, this can't derive `x` through `Transcript::challenge_scalar` or
+/// assemble a candidate through `CurveAffine::from_uncompressed_unchecked`: both need
+/// `G2`'s base field to implement `PrimeField`, which the extension field hosting `G2`
+/// does not (it's a degree-2 extension, not a field with a single canonical modulus).
+/// `TranscriptRng` and `CurveParameters::from_xy` sidestep that by working through
+/// `Field`'s `random`/`sqrt` instead of any byte encoding. Earlier code here instead
+/// hashed 64 digest bytes directly into `G2`'s 128-byte uncompressed encoding, leaving the
+/// other 64 bytes (`y`) zero every iteration, so the on-curve check only succeeded for the
+/// rare `x` with `y = 0` -- a loop that never terminates in practice.
+fn hash_to_g2<E: Engine>(
+    transcript_so_far: &ContributionHash,
+    s_g1: &E::G1Affine,
+    s_x_g1: &E::G1Affine,
+) -> E::G2
+where
+    E::G2Affine: CurveParameters,
+{
+    let mut transcript = Transcript::new(b"bellman extended-mpc hash-to-g2");
+    transcript.append_message(b"transcript", transcript_so_far);
+    transcript.append_message(b"s_g1", s_g1.to_uncompressed().as_ref());
+    transcript.append_message(b"s_x_g1", s_x_g1.to_uncompressed().as_ref());
+
+    let mut rng = TranscriptRng::new(&transcript);
+    loop {
+        let x: <E::G2Affine as CurveAffine>::Base = Field::random(&mut rng);
+        let x3b = x.square() * x + <E::G2Affine as CurveParameters>::coeff_b();
+        if let Some(y) = Option::from(x3b.sqrt()) {
+            if let Some(p) = E::G2Affine::from_xy(x, y) {
+                if !bool::from(p.is_identity()) {
+                    return p.to_curve();
+                }
+            }
+        }
+    }
+}
+
+/// An [`RngCore`] that deterministically expands a [`Transcript`] into an unbounded byte
+/// stream, by re-deriving [`Transcript::challenge_bytes`] under an incrementing label.
+/// [`hash_to_g2`] uses this to sample field elements via `Field::random` for curves (like
+/// `G2`'s extension-field base) that have no `PrimeField` repr to rejection-sample against
+/// directly.
+struct TranscriptRng<'a> {
+    transcript: &'a Transcript,
+    counter: u64,
+    buf: [u8; 64],
+    buf_pos: usize,
+}
+
+impl<'a> TranscriptRng<'a> {
+    fn new(transcript: &'a Transcript) -> Self {
+        TranscriptRng {
+            transcript,
+            counter: 0,
+            buf: [0u8; 64],
+            buf_pos: 64,
+        }
+    }
+}
+
+impl<'a> RngCore for TranscriptRng<'a> {
+    fn next_u32(&mut self) -> u32 {
+        rand_core::impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        let mut filled = 0;
+        while filled < dest.len() {
+            if self.buf_pos == self.buf.len() {
+                self.buf = self.transcript.challenge_bytes(&self.counter.to_be_bytes());
+                self.counter += 1;
+                self.buf_pos = 0;
+            }
+
+            let take = (self.buf.len() - self.buf_pos).min(dest.len() - filled);
+            dest[filled..filled + take]
+                .copy_from_slice(&self.buf[self.buf_pos..self.buf_pos + take]);
+            self.buf_pos += take;
+            filled += take;
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
@@ -0,0 +1,316 @@
+//! A [`ParameterSource`] backed by a memory-mapped parameter file.
+//!
+//! Groth16 proving keys for large circuits can run into the gigabytes, and a prover only
+//! ever streams through each query once. Rather than eagerly deserializing the whole file
+//! into `Vec<E::G1Affine>`/`Vec<E::G2Affine>` (which both duplicates the file's bytes in
+//! memory and pays the deserialization cost up front, even for portions of the key the
+//! circuit may not end up touching), `MappedParameters` `mmap`s the file once and records
+//! the byte range of each query; points are deserialized lazily, one at a time, as the
+//! prover's multiexps actually consume them.
+
+use std::io::{self, Read};
+use std::ops::Range;
+use std::sync::Arc;
+
+use group::CurveAffine;
+use memmap2::Mmap;
+
+use super::{ParameterSource, VerifyingKey};
+use crate::multiexp::SourceBuilder;
+use crate::SynthesisError;
+use pairing::Engine;
+
+/// Parameters for a Groth16 proof, backed by a `mmap`ed parameter file rather than
+/// in-memory `Vec`s.
+pub struct MappedParameters<E: Engine> {
+    pub vk: VerifyingKey<E>,
+    pub u32_size: usize,
+
+    /// The memory-mapped parameter file.
+    pub(crate) params: Arc<Mmap>,
+
+    /// Byte ranges of the `h`, `l`, `a`, `b_g1` and `b_g2` queries within `params`.
+    pub(crate) h: Vec<Range<usize>>,
+    pub(crate) l: Vec<Range<usize>>,
+    pub(crate) a: Vec<Range<usize>>,
+    pub(crate) b_g1: Vec<Range<usize>>,
+    pub(crate) b_g2: Vec<Range<usize>>,
+}
+
+impl<E: Engine> Clone for MappedParameters<E> {
+    fn clone(&self) -> Self {
+        MappedParameters {
+            vk: self.vk.clone(),
+            u32_size: self.u32_size,
+            params: self.params.clone(),
+            h: self.h.clone(),
+            l: self.l.clone(),
+            a: self.a.clone(),
+            b_g1: self.b_g1.clone(),
+            b_g2: self.b_g2.clone(),
+        }
+    }
+}
+
+fn range_of<T>(offset: &mut usize, count: usize, size: usize) -> Range<usize> {
+    let start = *offset;
+    let end = start + count * size;
+    *offset = end;
+    start..end
+}
+
+impl<E: Engine> MappedParameters<E> {
+    /// Memory-maps `file`, deserializes its verifying key, and records the byte ranges of
+    /// each query, without eagerly deserializing any query elements.
+    pub fn new(file: &std::fs::File) -> io::Result<Self> {
+        let mmap = unsafe { Mmap::map(file)? };
+
+        let g1_size = <E::G1Affine as CurveAffine>::Uncompressed::default()
+            .as_ref()
+            .len();
+        let g2_size = <E::G2Affine as CurveAffine>::Uncompressed::default()
+            .as_ref()
+            .len();
+        let u32_size = 4;
+
+        let mut reader = &mmap[..];
+        super::check_params_magic(&mut reader, super::PARAMS_UNCOMPRESSED)?;
+        let vk = VerifyingKey::<E>::read(&mut reader)?;
+
+        // `reader` has been advanced past the verifying key by `VerifyingKey::read`; the
+        // remaining queries live back-to-back, each preceded by a u32 length, starting
+        // from where it left off.
+        let mut offset = mmap.len() - reader.len();
+
+        let mut read_range = |size: usize| -> io::Result<Vec<Range<usize>>> {
+            let count = (&mmap[offset..offset + u32_size]).read_u32_shim()? as usize;
+            offset += u32_size;
+            Ok((0..count)
+                .map(|_| range_of::<()>(&mut offset, 1, size))
+                .collect())
+        };
+
+        let h = read_range(g1_size)?;
+        let l = read_range(g1_size)?;
+        let a = read_range(g1_size)?;
+        let b_g1 = read_range(g1_size)?;
+        let b_g2 = read_range(g2_size)?;
+
+        Ok(MappedParameters {
+            vk,
+            u32_size,
+            params: Arc::new(mmap),
+            h,
+            l,
+            a,
+            b_g1,
+            b_g2,
+        })
+    }
+
+    fn read_g1(&self, range: Range<usize>) -> io::Result<E::G1Affine> {
+        let mut repr = <E::G1Affine as CurveAffine>::Uncompressed::default();
+        repr.as_mut().copy_from_slice(&self.params[range]);
+        let affine = E::G1Affine::from_uncompressed_unchecked(&repr);
+        if affine.is_some().into() {
+            Ok(affine.unwrap())
+        } else {
+            Err(io::Error::new(io::ErrorKind::InvalidData, "invalid G1"))
+        }
+    }
+
+    fn read_g2(&self, range: Range<usize>) -> io::Result<E::G2Affine> {
+        let mut repr = <E::G2Affine as CurveAffine>::Uncompressed::default();
+        repr.as_mut().copy_from_slice(&self.params[range]);
+        let affine = E::G2Affine::from_uncompressed_unchecked(&repr);
+        if affine.is_some().into() {
+            Ok(affine.unwrap())
+        } else {
+            Err(io::Error::new(io::ErrorKind::InvalidData, "invalid G2"))
+        }
+    }
+}
+
+/// A [`SourceBuilder`] that lazily deserializes `E::G1Affine`/`E::G2Affine` elements out
+/// of a `mmap`ed parameter file, one point at a time, instead of holding the whole query
+/// as an in-memory `Vec`.
+#[derive(Clone)]
+pub struct MappedSourceBuilder<E: Engine> {
+    params: Arc<MappedParameters<E>>,
+    ranges: Arc<Vec<Range<usize>>>,
+    is_g2: bool,
+    offset: usize,
+}
+
+impl<E: Engine> SourceBuilder<E::G1Affine> for MappedSourceBuilder<E> {
+    type Source = (Self, usize);
+
+    fn new(self) -> (Self::Source, Self::Source) {
+        ((self.clone(), 0), (self, 0))
+    }
+
+    fn get(&mut self) -> Result<(E::G1Affine, usize), SynthesisError> {
+        unreachable!("use the (Self, usize) Source impl instead")
+    }
+}
+
+impl<E: Engine> SourceBuilder<E::G2Affine> for MappedSourceBuilder<E> {
+    type Source = (Self, usize);
+
+    fn new(self) -> (Self::Source, Self::Source) {
+        ((self.clone(), 0), (self, 0))
+    }
+
+    fn get(&mut self) -> Result<(E::G2Affine, usize), SynthesisError> {
+        unreachable!("use the (Self, usize) Source impl instead")
+    }
+}
+
+impl<E: Engine> crate::multiexp::Source<E::G1Affine> for (MappedSourceBuilder<E>, usize) {
+    fn add_assign_mixed(
+        &mut self,
+        to: &mut <E::G1Affine as CurveAffine>::Projective,
+    ) -> Result<(), SynthesisError> {
+        let (builder, idx) = self;
+        let range = builder.ranges[builder.offset + *idx].clone();
+        let p = builder
+            .params
+            .read_g1(range)
+            .map_err(|_| SynthesisError::MalformedCrs)?;
+        CurveAffine::add_assign_mixed(to, &p);
+        *idx += 1;
+        Ok(())
+    }
+
+    fn skip(&mut self, amt: usize) -> Result<(), SynthesisError> {
+        self.1 += amt;
+        Ok(())
+    }
+}
+
+impl<E: Engine> crate::multiexp::Source<E::G2Affine> for (MappedSourceBuilder<E>, usize) {
+    fn add_assign_mixed(
+        &mut self,
+        to: &mut <E::G2Affine as CurveAffine>::Projective,
+    ) -> Result<(), SynthesisError> {
+        let (builder, idx) = self;
+        let range = builder.ranges[builder.offset + *idx].clone();
+        let p = builder
+            .params
+            .read_g2(range)
+            .map_err(|_| SynthesisError::MalformedCrs)?;
+        CurveAffine::add_assign_mixed(to, &p);
+        *idx += 1;
+        Ok(())
+    }
+
+    fn skip(&mut self, amt: usize) -> Result<(), SynthesisError> {
+        self.1 += amt;
+        Ok(())
+    }
+}
+
+impl<E: Engine> ParameterSource<E> for Arc<MappedParameters<E>> {
+    type G1Builder = MappedSourceBuilder<E>;
+    type G2Builder = MappedSourceBuilder<E>;
+
+    fn get_vk(&mut self, _: usize) -> Result<VerifyingKey<E>, SynthesisError> {
+        Ok(self.vk.clone())
+    }
+
+    fn get_h(&mut self, _: usize) -> Result<Self::G1Builder, SynthesisError> {
+        Ok(MappedSourceBuilder {
+            params: self.clone(),
+            ranges: Arc::new(self.h.clone()),
+            is_g2: false,
+            offset: 0,
+        })
+    }
+
+    fn get_l(&mut self, _: usize) -> Result<Self::G1Builder, SynthesisError> {
+        Ok(MappedSourceBuilder {
+            params: self.clone(),
+            ranges: Arc::new(self.l.clone()),
+            is_g2: false,
+            offset: 0,
+        })
+    }
+
+    fn get_a(
+        &mut self,
+        num_inputs: usize,
+        _: usize,
+    ) -> Result<(Self::G1Builder, Self::G1Builder), SynthesisError> {
+        let ranges = Arc::new(self.a.clone());
+        Ok((
+            MappedSourceBuilder {
+                params: self.clone(),
+                ranges: ranges.clone(),
+                is_g2: false,
+                offset: 0,
+            },
+            MappedSourceBuilder {
+                params: self.clone(),
+                ranges,
+                is_g2: false,
+                offset: num_inputs,
+            },
+        ))
+    }
+
+    fn get_b_g1(
+        &mut self,
+        num_inputs: usize,
+        _: usize,
+    ) -> Result<(Self::G1Builder, Self::G1Builder), SynthesisError> {
+        let ranges = Arc::new(self.b_g1.clone());
+        Ok((
+            MappedSourceBuilder {
+                params: self.clone(),
+                ranges: ranges.clone(),
+                is_g2: false,
+                offset: 0,
+            },
+            MappedSourceBuilder {
+                params: self.clone(),
+                ranges,
+                is_g2: false,
+                offset: num_inputs,
+            },
+        ))
+    }
+
+    fn get_b_g2(
+        &mut self,
+        num_inputs: usize,
+        _: usize,
+    ) -> Result<(Self::G2Builder, Self::G2Builder), SynthesisError> {
+        let ranges = Arc::new(self.b_g2.clone());
+        Ok((
+            MappedSourceBuilder {
+                params: self.clone(),
+                ranges: ranges.clone(),
+                is_g2: true,
+                offset: 0,
+            },
+            MappedSourceBuilder {
+                params: self.clone(),
+                ranges,
+                is_g2: true,
+                offset: num_inputs,
+            },
+        ))
+    }
+}
+
+trait ReadU32Shim {
+    fn read_u32_shim(&mut self) -> io::Result<u32>;
+}
+
+impl ReadU32Shim for &[u8] {
+    fn read_u32_shim(&mut self) -> io::Result<u32> {
+        let mut buf = [0u8; 4];
+        self.read_exact(&mut buf)?;
+        Ok(u32::from_be_bytes(buf))
+    }
+}
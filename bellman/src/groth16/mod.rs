@@ -4,11 +4,12 @@
 
 use group::{CurveAffine, CurveProjective, Group};
 use pairing::{Engine, PairingCurveAffine};
-use ff::{Field};
+use ff::{Field, PrimeField};
 
 use crate::{SynthesisError, Circuit, ConstraintSystem, Index, Variable, LinearCombination};
 use crate::domain::{EvaluationDomain, Point};
-use crate::multiexp::{multiexp, FullDensity, SourceBuilder, DensityTracker};
+use crate::gpu;
+use crate::multiexp::{FullDensity, SourceBuilder, DensityTracker};
 use crate::multicore::Worker;
 
 use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
@@ -22,12 +23,22 @@ use std::ops::{AddAssign, Neg, MulAssign};
 #[cfg(test)]
 mod tests;
 
+mod batch;
+mod extended_mpc;
 mod generator;
+mod mapped_params;
+mod mpc;
 mod prover;
+mod transcript;
 mod verifier;
 
+pub use self::batch::*;
+pub use self::extended_mpc::*;
 pub use self::generator::*;
+pub use self::mapped_params::*;
+pub use self::mpc::*;
 pub use self::prover::*;
+pub use self::transcript::Transcript;
 pub use self::verifier::*;
 
 #[derive(Clone)]
@@ -224,6 +235,53 @@ impl<E: Engine> VerifyingKey<E> {
             ic,
         })
     }
+
+    /// Like [`Self::write`], but serializes every point with `to_compressed`, halving the
+    /// encoded size at the cost of requiring decompression (and, if `checked` is set during
+    /// [`Self::read_compressed`], a subgroup check) on load.
+    pub fn write_compressed<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writer.write_all(self.alpha_g1.to_compressed().as_ref())?;
+        writer.write_all(self.beta_g1.to_compressed().as_ref())?;
+        writer.write_all(self.beta_g2.to_compressed().as_ref())?;
+        writer.write_all(self.gamma_g2.to_compressed().as_ref())?;
+        writer.write_all(self.delta_g1.to_compressed().as_ref())?;
+        writer.write_all(self.delta_g2.to_compressed().as_ref())?;
+        writer.write_u32::<BigEndian>(self.ic.len() as u32)?;
+        for ic in &self.ic {
+            writer.write_all(ic.to_compressed().as_ref())?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads a [`VerifyingKey`] written by [`Self::write_compressed`]. When `checked` is
+    /// set, every decompressed point is additionally verified to lie in the correct
+    /// subgroup, matching the semantics of [`Parameters::read_compressed`]'s `checked` flag.
+    pub fn read_compressed<R: Read>(mut reader: R, checked: bool) -> io::Result<Self> {
+        let alpha_g1 = read_g1_compressed::<R, E>(&mut reader, checked)?;
+        let beta_g1 = read_g1_compressed::<R, E>(&mut reader, checked)?;
+        let beta_g2 = read_g2_compressed::<R, E>(&mut reader, checked)?;
+        let gamma_g2 = read_g2_compressed::<R, E>(&mut reader, checked)?;
+        let delta_g1 = read_g1_compressed::<R, E>(&mut reader, checked)?;
+        let delta_g2 = read_g2_compressed::<R, E>(&mut reader, checked)?;
+
+        let ic_len = reader.read_u32::<BigEndian>()? as usize;
+
+        let mut ic = vec![];
+        for _ in 0..ic_len {
+            ic.push(read_g1_compressed::<R, E>(&mut reader, checked)?);
+        }
+
+        Ok(VerifyingKey {
+            alpha_g1,
+            beta_g1,
+            beta_g2,
+            gamma_g2,
+            delta_g1,
+            delta_g2,
+            ic,
+        })
+    }
 }
 
 #[derive(Clone)]
@@ -319,8 +377,82 @@ fn read_g2<R: Read, E: Engine>(reader: &mut R, checked: bool) -> io::Result<E::G
     })
 }
 
+fn read_g1_compressed<R: Read, E: Engine>(reader: &mut R, checked: bool) -> io::Result<E::G1Affine> {
+    let mut repr = <E::G1Affine as CurveAffine>::Compressed::default();
+    reader.read_exact(repr.as_mut())?;
+
+    let affine = if checked {
+        E::G1Affine::from_compressed(&repr)
+    } else {
+        E::G1Affine::from_compressed_unchecked(&repr)
+    };
+
+    let affine = if affine.is_some().into() {
+        Ok(affine.unwrap())
+    } else {
+        Err(io::Error::new(io::ErrorKind::InvalidData, "invalid G1"))
+    };
+
+    affine.and_then(|e| {
+        if e.is_identity().into() {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "point at infinity",
+            ))
+        } else {
+            Ok(e)
+        }
+    })
+}
+
+fn read_g2_compressed<R: Read, E: Engine>(reader: &mut R, checked: bool) -> io::Result<E::G2Affine> {
+    let mut repr = <E::G2Affine as CurveAffine>::Compressed::default();
+    reader.read_exact(repr.as_mut())?;
+
+    let affine = if checked {
+        E::G2Affine::from_compressed(&repr)
+    } else {
+        E::G2Affine::from_compressed_unchecked(&repr)
+    };
+
+    let affine = if affine.is_some().into() {
+        Ok(affine.unwrap())
+    } else {
+        Err(io::Error::new(io::ErrorKind::InvalidData, "invalid G2"))
+    };
+
+    affine.and_then(|e| {
+        if e.is_identity().into() {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "point at infinity",
+            ))
+        } else {
+            Ok(e)
+        }
+    })
+}
+
+/// On-disk tag distinguishing [`Parameters::write`]'s uncompressed encoding from
+/// [`Parameters::write_compressed`]'s, so [`Parameters::read`]/[`Parameters::read_compressed`]
+/// can reject a file serialized with the other encoding instead of misinterpreting its bytes.
+const PARAMS_UNCOMPRESSED: u8 = 0;
+const PARAMS_COMPRESSED: u8 = 1;
+
+fn check_params_magic<R: Read>(reader: &mut R, expected: u8) -> io::Result<()> {
+    let magic = reader.read_u8()?;
+    if magic != expected {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "parameters encoding tag does not match the requested read method",
+        ));
+    }
+    Ok(())
+}
+
 impl<E: Engine> Parameters<E> {
     pub fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writer.write_u8(PARAMS_UNCOMPRESSED)?;
         self.vk.write(&mut writer)?;
 
         writer.write_u32::<BigEndian>(self.h.len() as u32)?;
@@ -352,6 +484,8 @@ impl<E: Engine> Parameters<E> {
     }
 
     pub fn read<R: Read>(mut reader: R, checked: bool) -> io::Result<Self> {
+        check_params_magic(&mut reader, PARAMS_UNCOMPRESSED)?;
+
         let vk = VerifyingKey::<E>::read(&mut reader)?;
 
         let mut h = vec![];
@@ -404,9 +538,110 @@ impl<E: Engine> Parameters<E> {
             b_g2: Arc::new(b_g2),
         })
     }
+
+    /// Like [`Self::write`], but serializes the verifying key and every query point with
+    /// point compression (`to_compressed`), roughly halving the on-disk size versus
+    /// [`Self::write`]'s `to_uncompressed` encoding. The section-length framing is
+    /// otherwise identical; only the leading tag byte and per-point encoding differ.
+    pub fn write_compressed<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writer.write_u8(PARAMS_COMPRESSED)?;
+        self.vk.write_compressed(&mut writer)?;
+
+        writer.write_u32::<BigEndian>(self.h.len() as u32)?;
+        for g in &self.h[..] {
+            writer.write_all(g.to_compressed().as_ref())?;
+        }
+
+        writer.write_u32::<BigEndian>(self.l.len() as u32)?;
+        for g in &self.l[..] {
+            writer.write_all(g.to_compressed().as_ref())?;
+        }
+
+        writer.write_u32::<BigEndian>(self.a.len() as u32)?;
+        for g in &self.a[..] {
+            writer.write_all(g.to_compressed().as_ref())?;
+        }
+
+        writer.write_u32::<BigEndian>(self.b_g1.len() as u32)?;
+        for g in &self.b_g1[..] {
+            writer.write_all(g.to_compressed().as_ref())?;
+        }
+
+        writer.write_u32::<BigEndian>(self.b_g2.len() as u32)?;
+        for g in &self.b_g2[..] {
+            writer.write_all(g.to_compressed().as_ref())?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads `Parameters` written by [`Self::write_compressed`]. `checked` controls
+    /// whether decompression also performs the full subgroup check (see
+    /// [`group::CurveAffine::from_compressed`] vs. `from_compressed_unchecked`), exactly
+    /// as it does for [`Self::read`].
+    pub fn read_compressed<R: Read>(mut reader: R, checked: bool) -> io::Result<Self> {
+        check_params_magic(&mut reader, PARAMS_COMPRESSED)?;
+
+        let vk = VerifyingKey::<E>::read_compressed(&mut reader, checked)?;
+
+        let mut h = vec![];
+        let mut l = vec![];
+        let mut a = vec![];
+        let mut b_g1 = vec![];
+        let mut b_g2 = vec![];
+
+        {
+            let len = reader.read_u32::<BigEndian>()? as usize;
+            for _ in 0..len {
+                h.push(read_g1_compressed::<R, E>(&mut reader, checked)?);
+            }
+        }
+
+        {
+            let len = reader.read_u32::<BigEndian>()? as usize;
+            for _ in 0..len {
+                l.push(read_g1_compressed::<R, E>(&mut reader, checked)?);
+            }
+        }
+
+        {
+            let len = reader.read_u32::<BigEndian>()? as usize;
+            for _ in 0..len {
+                a.push(read_g1_compressed::<R, E>(&mut reader, checked)?);
+            }
+        }
+
+        {
+            let len = reader.read_u32::<BigEndian>()? as usize;
+            for _ in 0..len {
+                b_g1.push(read_g1_compressed::<R, E>(&mut reader, checked)?);
+            }
+        }
+
+        {
+            let len = reader.read_u32::<BigEndian>()? as usize;
+            for _ in 0..len {
+                b_g2.push(read_g2_compressed::<R, E>(&mut reader, checked)?);
+            }
+        }
+
+        Ok(Parameters {
+            vk,
+            h: Arc::new(h),
+            l: Arc::new(l),
+            a: Arc::new(a),
+            b_g1: Arc::new(b_g1),
+            b_g2: Arc::new(b_g2),
+        })
+    }
 }
 
 pub struct PreparedVerifyingKey<E: Engine> {
+    /// alpha in G1, kept alongside the combined pairing result so that batch
+    /// verification can fold it into a single multi-Miller loop.
+    alpha_g1: E::G1Affine,
+    /// beta in G2, prepared.
+    beta_g2: <E::G2Affine as PairingCurveAffine>::Prepared,
     /// Pairing result of alpha*beta
     alpha_g1_beta_g2: E::Fqk,
     /// -gamma in G2
@@ -603,6 +838,11 @@ pub struct ExtendedParameters<E: Engine> {
 
     pub taus_g1: Vec<E::G1Affine>,
     pub taus_g2: Vec<E::G2Affine>,
+
+    /// A digest of the constraint system these parameters were built for, as computed by
+    /// `extended_mpc::digest_assembly`. `verify` recomputes it from the circuit it is
+    /// handed and rejects a mismatch up front, before doing any pairing work.
+    pub cs_digest: ContributionHash,
 }
 
 impl<E: Engine> PartialEq for ExtendedParameters<E> {
@@ -610,9 +850,24 @@ impl<E: Engine> PartialEq for ExtendedParameters<E> {
         self.params == other.params
             && self.taus_g1 == other.taus_g1
             && self.taus_g2 == other.taus_g2
+            && self.cs_digest == other.cs_digest
     }
 }
 
+/// The interpolated QAP query vectors and per-wire density trackers recomputed by
+/// `ExtendedParameters::verify_into` while checking a CRS against a circuit, returned so
+/// downstream tooling (MPC verification, re-serialization) can reuse them without paying
+/// for a second synthesis pass.
+pub struct QapArtifacts<E: Engine> {
+    pub a_g1: Arc<Vec<E::G1Affine>>,
+    pub b_g1: Arc<Vec<E::G1Affine>>,
+    pub b_g2: Arc<Vec<E::G2Affine>>,
+    pub c_g1: Arc<Vec<E::G1Affine>>,
+    pub at_density: Arc<DensityTracker>,
+    pub bt_density: Arc<DensityTracker>,
+    pub ct_density: Arc<DensityTracker>,
+}
+
 impl<E: Engine> ExtendedParameters<E> {
 
     // Checks the CRS for possible subversion by the malicious generator. It does not guarantee subversion soundness,
@@ -622,13 +877,105 @@ impl<E: Engine> ExtendedParameters<E> {
     // Then the verifier can be sure in the soundness as only it knows the trapdoor, and the prover is given it's privacy.
     // Follows the procedure from Georg Fuchsbauer, Subversion-zero-knowledge SNARKs (https://eprint.iacr.org/2017/587), p. 26
     pub fn verify<C: Circuit<E>, R: RngCore>(&self, circuit: C, rng: &mut R) -> Result<(), SynthesisError> {
+        self.verify_into(circuit, rng).map(|_| ())
+    }
+
+    /// Same check as [`Self::verify`], but returns the interpolated QAP query vectors and
+    /// density trackers it recomputed along the way instead of discarding them.
+    pub fn verify_into<C: Circuit<E>, R: RngCore>(&self, circuit: C, rng: &mut R) -> Result<QapArtifacts<E>, SynthesisError> {
+        self.verify_into_with(circuit, &mut || E::Fr::random(&mut *rng))
+    }
+
+    /// Same check as [`Self::verify`], but the small-exponent test scalars `r`, `p`, `q`
+    /// are squeezed deterministically from a [`Transcript`] bound to this CRS (the
+    /// serialized `VerifyingKey`, `taus_g1`, `taus_g2` and `h`) instead of drawn from an
+    /// `RngCore`. Two verifiers checking the same `ExtendedParameters` this way always
+    /// compute the same challenges, so the check is reproducible without needing to share
+    /// or log any randomness -- useful for consensus-critical re-verification, or for
+    /// comparing a verification run against a published transcript.
+    pub fn verify_with_transcript<C: Circuit<E>>(&self, circuit: C) -> Result<(), SynthesisError> {
+        self.verify_into_with_transcript(circuit).map(|_| ())
+    }
+
+    /// Same check as [`Self::verify_into`], but with [`Self::verify_with_transcript`]'s
+    /// deterministic challenge derivation.
+    pub fn verify_into_with_transcript<C: Circuit<E>>(
+        &self,
+        circuit: C,
+    ) -> Result<QapArtifacts<E>, SynthesisError> {
+        let mut transcript = Transcript::new(b"bellman extended-mpc subversion check");
+        let mut vk_bytes = vec![];
+        self.params
+            .vk
+            .write(&mut vk_bytes)
+            .expect("writing to a Vec cannot fail");
+        transcript.append_message(b"vk", &vk_bytes);
+        for tau_g1 in &self.taus_g1 {
+            transcript.append_message(b"taus_g1", tau_g1.to_uncompressed().as_ref());
+        }
+        for tau_g2 in &self.taus_g2 {
+            transcript.append_message(b"taus_g2", tau_g2.to_uncompressed().as_ref());
+        }
+        for h in self.params.h.iter() {
+            transcript.append_message(b"h", h.to_uncompressed().as_ref());
+        }
+
+        let mut counter: u64 = 0;
+        self.verify_into_with(circuit, &mut || {
+            let scalar = transcript.challenge_scalar(&counter.to_be_bytes());
+            counter += 1;
+            scalar
+        })
+    }
+
+    /// Shared implementation behind [`Self::verify_into`] and
+    /// [`Self::verify_into_with_transcript`], parameterised over where the small-exponent
+    /// test scalars come from.
+    fn verify_into_with<C: Circuit<E>>(
+        &self,
+        circuit: C,
+        next_scalar: &mut dyn FnMut() -> E::Fr,
+    ) -> Result<QapArtifacts<E>, SynthesisError> {
         assert_eq!(self.taus_g1.len(), self.taus_g2.len());
         // generator points
         let g1 = self.taus_g1[0];
         let g2 = self.taus_g2[0];
 
+        // R1CS -> QAP in Lagrange base
+        // TODO: we don't need to distinguish input and auxiliary wires here
+        let mut assembly = KeypairAssembly {
+            num_inputs: 0,
+            num_aux: 0,
+            num_constraints: 0,
+            at_inputs: vec![],
+            bt_inputs: vec![],
+            ct_inputs: vec![],
+            at_aux: vec![],
+            bt_aux: vec![],
+            ct_aux: vec![],
+        };
+
+        // Allocate the "one" input variable
+        assembly.alloc_input(|| "", || Ok(E::Fr::one()))?;
+
+        // Synthesize the circuit.
+        circuit.synthesize(&mut assembly)?;
+
+        // Input constraints to ensure full density of IC query
+        // x * 0 = 0
+        for i in 0..assembly.num_inputs {
+            assembly.enforce(|| "", |lc| lc + Variable(Index::Input(i)), |lc| lc, |lc| lc);
+        }
+
+        // Cheap pre-check: reject a circuit that doesn't match the one these parameters
+        // were built for before doing any pairing work.
+        if digest_assembly::<E>(&assembly) != self.cs_digest {
+            return Err(SynthesisError::MalformedCrs);
+        }
+
         let d = self.taus_g1.len() - 1;
         let worker = Worker::new();
+        let mut multiexp_kernel = gpu::LockedMultiexpKernel::<E>::new();
 
         let pvk = prepare_verifying_key(&self.params.vk);
 
@@ -686,7 +1033,7 @@ impl<E: Engine> ExtendedParameters<E> {
             let h_query = start_timer!(|| "H-query validation");
 
             let mut r = vec![];
-            r.resize_with(d, || { E::Fr::random(rng) });
+            r.resize_with(d, || { next_scalar() });
             let r = Arc::new(r);
 
             let taus_g2 = Arc::new(self.taus_g2.clone().into_iter().take(d).collect::<Vec<_>>()); // tau^0, ..., tau^(d-1) in G2
@@ -696,9 +1043,9 @@ impl<E: Engine> ExtendedParameters<E> {
             assert_eq!(taus_g2.len(), d);
             assert_eq!(r.len(), d);
 
-            let acc_h_g1: E::G1 = multiexp(&worker, (self.params.h.clone(), 0), FullDensity, r.clone()).wait().unwrap();
-            let acc_taus_g2: E::G2 = multiexp(&worker, (taus_g2, 0), FullDensity, r.clone()).wait().unwrap();
-            let acc_taus_g2_shifted: E::G2 = multiexp(&worker, (taus_g2_shifted, 0), FullDensity, r).wait().unwrap();
+            let acc_h_g1: E::G1 = gpu::multiexp(&mut multiexp_kernel, &worker, (self.params.h.clone(), 0), FullDensity, r.clone()).wait().unwrap();
+            let acc_taus_g2: E::G2 = gpu::multiexp(&mut multiexp_kernel, &worker, (taus_g2, 0), FullDensity, r.clone()).wait().unwrap();
+            let acc_taus_g2_shifted: E::G2 = gpu::multiexp(&mut multiexp_kernel, &worker, (taus_g2_shifted, 0), FullDensity, r).wait().unwrap();
 
             // The vanishing polynomial is z(X) = X^{d+1} - 1 for our domain, where d+1 is domain size
             let res = E::final_exponentiation(&E::miller_loop(
@@ -721,8 +1068,8 @@ impl<E: Engine> ExtendedParameters<E> {
             let mut q = vec![];
 
             // TODO: 128-bit scalar multiexps
-            p.resize_with(d, || { E::Fr::random(rng) });
-            q.resize_with(d, || { E::Fr::random(rng) });
+            p.resize_with(d, || { next_scalar() });
+            q.resize_with(d, || { next_scalar() });
 
             let mut pq = p.clone();
             for (p, q) in pq.iter_mut().zip(q.iter()) {
@@ -737,9 +1084,9 @@ impl<E: Engine> ExtendedParameters<E> {
             let bases_p = Arc::new(self.taus_g1.clone().into_iter().take(d).collect()); // tau^0, ..., tau^(d-1) in G1
             let bases_q = Arc::new(self.taus_g2.clone().into_iter().skip(1).collect()); // tau^1, ..., tau^d in G2
 
-            let pq_tau_g1: E::G1 = multiexp(&worker, (bases_pq, 0), FullDensity, pq).wait().unwrap();
-            let p_tau_g1: E::G1 = multiexp(&worker, (bases_p, 0), FullDensity, p).wait().unwrap();
-            let q_tau_g2: E::G2 = multiexp(&worker, (bases_q, 0), FullDensity, q).wait().unwrap();
+            let pq_tau_g1: E::G1 = gpu::multiexp(&mut multiexp_kernel, &worker, (bases_pq, 0), FullDensity, pq).wait().unwrap();
+            let p_tau_g1: E::G1 = gpu::multiexp(&mut multiexp_kernel, &worker, (bases_p, 0), FullDensity, p).wait().unwrap();
+            let q_tau_g2: E::G2 = gpu::multiexp(&mut multiexp_kernel, &worker, (bases_q, 0), FullDensity, q).wait().unwrap();
             //TODO: i guess joining wouldn't help
 
             let g1 = self.taus_g1[0];
@@ -760,59 +1107,32 @@ impl<E: Engine> ExtendedParameters<E> {
 
         // Convert the circuit in R1CS to the QAP in Lagrange base (QAP polynomials evaluations in the roots of unity)
         // The additional input and constraints are Groth16/bellman specific, see the code in generator or prover
-
-        let qap_synthesis = start_timer!(|| "QAP synthesis");
-        // TODO: we don't need to distinguish input and auxiliary wires here
-        let mut assembly = KeypairAssembly {
-            num_inputs: 0,
-            num_aux: 0,
-            num_constraints: 0,
-            at_inputs: vec![],
-            bt_inputs: vec![],
-            ct_inputs: vec![],
-            at_aux: vec![],
-            bt_aux: vec![],
-            ct_aux: vec![],
-        };
-
-        // Allocate the "one" input variable
-        assembly.alloc_input(|| "", || Ok(E::Fr::one()))?;
-
-        // Synthesize the circuit.
-        circuit.synthesize(&mut assembly)?;
-
-        // Input constraints to ensure full density of IC query
-        // x * 0 = 0
-        for i in 0..assembly.num_inputs {
-            assembly.enforce(|| "", |lc| lc + Variable(Index::Input(i)), |lc| lc, |lc| lc);
-        }
-
-        // R1CS -> QAP in Lagrange base
-        end_timer!(qap_synthesis);
+        // (`assembly` was already synthesized above, for the cs_digest pre-check)
 
         // Evaluate the QAP polynomials in point tau in the exponent
 
         let qap_evaluation = start_timer!(|| "QAP evaluation");
         // The code bellow is borrowed from https://github.com/ebfull/powersoftau/blob/5429415959175082207fd61c10319e47a6b56e87/src/bin/verify.rs#L162-L225
         let worker = Worker::new();
+        let mut fft_kernel = gpu::LockedFftKernel::<E>::new();
 
         let mut g1_coeffs = EvaluationDomain::<E, _>::from_coeffs(
             self.taus_g1.iter()
             .map(|e| Point(e.to_projective()))
             .collect()
-        ).unwrap(); //TODO: remove Arc?
+        )?; //TODO: remove Arc?
 
         let mut g2_coeffs = EvaluationDomain::<E, _>::from_coeffs(
             self.taus_g2.iter()
                 .map(|e| Point(e.to_projective()))
                 .collect()
-        ).unwrap(); //TODO: remove Arc?
+        )?; //TODO: remove Arc?
 
         // This converts all of the elements into Lagrange coefficients
         // for later construction of interpolation polynomials
 
-        g1_coeffs.ifft(&worker);
-        g2_coeffs.ifft(&worker);
+        gpu::ifft(&mut fft_kernel, &mut g1_coeffs, &worker);
+        gpu::ifft(&mut fft_kernel, &mut g2_coeffs, &worker);
         let g1_coeffs = g1_coeffs.into_coeffs();
         let g2_coeffs = g2_coeffs.into_coeffs();
 
@@ -937,13 +1257,18 @@ impl<E: Engine> ExtendedParameters<E> {
         //TODO: sizes
         assert_eq!(self.params.l.len(), assembly.num_aux);
 
+        let at_density = Arc::new(get_density(at));
+        let bt_density = Arc::new(get_density(bt));
+        let ct_density = Arc::new(get_density(ct));
+
         {
             let worker = Worker::new();
+            let mut multiexp_kernel = gpu::LockedMultiexpKernel::<E>::new();
 
             let circuit_validation = start_timer!(|| "circuit validation");
 
             let mut z = vec![];
-            z.resize_with(num_wires, || { E::Fr::random(rng) });
+            z.resize_with(num_wires, || { next_scalar() });
             let mut z_inp = z.clone();
             let z_aux = z_inp.split_off( assembly.num_inputs);
 
@@ -951,11 +1276,11 @@ impl<E: Engine> ExtendedParameters<E> {
             let z_inp = Arc::new(z_inp);
             let z_aux = Arc::new(z_aux);
 
-            let acc_a_g1: E::G1 = multiexp(&worker, (a_g1_affine.clone(), 0), Arc::new(get_density(at)), z.clone()).wait().unwrap();
-            let acc_b_g2: E::G2 = multiexp(&worker, (b_g2_affine.clone(), 0), Arc::new(get_density(bt)), z.clone()).wait().unwrap();
-            let acc_c_g1: E::G1 = multiexp(&worker, (c_g1_affine, 0), Arc::new(get_density(ct)), z).wait().unwrap();
-            let acc_l_g1: E::G1 = multiexp(&worker, (self.params.l.clone(), 0), FullDensity, z_aux).wait().unwrap();
-            let acc_ic_g1: E::G1 = multiexp(&worker, (Arc::new(self.params.vk.ic.clone()), 0), FullDensity, z_inp).wait().unwrap();
+            let acc_a_g1: E::G1 = gpu::multiexp(&mut multiexp_kernel, &worker, (a_g1_affine.clone(), 0), at_density.clone(), z.clone()).wait().unwrap();
+            let acc_b_g2: E::G2 = gpu::multiexp(&mut multiexp_kernel, &worker, (b_g2_affine.clone(), 0), bt_density.clone(), z.clone()).wait().unwrap();
+            let acc_c_g1: E::G1 = gpu::multiexp(&mut multiexp_kernel, &worker, (c_g1_affine.clone(), 0), ct_density.clone(), z).wait().unwrap();
+            let acc_l_g1: E::G1 = gpu::multiexp(&mut multiexp_kernel, &worker, (self.params.l.clone(), 0), FullDensity, z_aux).wait().unwrap();
+            let acc_ic_g1: E::G1 = gpu::multiexp(&mut multiexp_kernel, &worker, (Arc::new(self.params.vk.ic.clone()), 0), FullDensity, z_inp).wait().unwrap();
 
             let res = E::final_exponentiation(&E::miller_loop(
                 [
@@ -974,12 +1299,19 @@ impl<E: Engine> ExtendedParameters<E> {
         }
 
         // Check that QAP polynomial evaluations given in the CRS coincide with those computed above
-        // TODO: return polys
         assert_eq!(a_g1_affine, self.params.a);
         assert_eq!(b_g1_affine, self.params.b_g1);
         assert_eq!(b_g2_affine, self.params.b_g2);
 
-        Ok(())
+        Ok(QapArtifacts {
+            a_g1: a_g1_affine,
+            b_g1: b_g1_affine,
+            b_g2: b_g2_affine,
+            c_g1: c_g1_affine,
+            at_density,
+            bt_density,
+            ct_density,
+        })
     }
 
     pub fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
@@ -995,6 +1327,8 @@ impl<E: Engine> ExtendedParameters<E> {
             writer.write_all(g.to_uncompressed().as_ref())?;
         }
 
+        writer.write_all(&self.cs_digest)?;
+
         Ok(())
     }
 
@@ -1018,10 +1352,24 @@ impl<E: Engine> ExtendedParameters<E> {
             }
         }
 
+        // `verify` builds an `EvaluationDomain` out of `taus_g1`/`taus_g2`, which only
+        // supports domains up to the scalar field's two-adicity; catch an oversized CRS
+        // here with a typed error instead of letting `verify` hit it as a panic.
+        if taus_g1.len() > (1 << E::Fr::S) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "tau powers exceed the scalar field's two-adic domain size",
+            ));
+        }
+
+        let mut cs_digest: ContributionHash = [0u8; 64];
+        reader.read_exact(&mut cs_digest)?;
+
         Ok(ExtendedParameters {
             params: params,
             taus_g1: taus_g1,
             taus_g2: taus_g2,
+            cs_digest: cs_digest,
         })
     }
 }
@@ -1140,4 +1488,18 @@ mod test_with_bls12_381 {
         let params = generate_extended_random_parameters::<Bls12, _, _>(MySillyCircuit { a: None, b: None }, rng).unwrap();
         assert!(params.verify(MySillyCircuit { a: None, b: None }, rng).is_ok());
     }
+
+    #[test]
+    fn subversion_check_with_transcript() {
+        let rng = &mut thread_rng();
+        let params = generate_extended_random_parameters::<Bls12, _, _>(MySillyCircuit { a: None, b: None }, rng).unwrap();
+        // Two independent calls against the same params must derive the same challenges
+        // and reach the same (successful) verdict, with no rng involved at all.
+        assert!(params
+            .verify_with_transcript(MySillyCircuit { a: None, b: None })
+            .is_ok());
+        assert!(params
+            .verify_with_transcript(MySillyCircuit { a: None, b: None })
+            .is_ok());
+    }
 }
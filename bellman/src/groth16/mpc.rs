@@ -0,0 +1,255 @@
+//! Phase-2 MPC ceremony support.
+//!
+//! Groth16's CRS depends on a toxic-waste value `tau`, but it is also parameterised by an
+//! independent `delta` value that only affects the `h`, `l`, and the `delta_g1`/`delta_g2`
+//! components of [`Parameters`]. [`MPCParameters`] lets a chain of participants each
+//! multiply `delta` by a private contribution, re-randomizing those components without
+//! needing to redo the (much more expensive) `tau`-dependent phase 1 setup, as long as at
+//! least one participant in the chain destroys their contribution.
+
+use std::io::{self, Read, Write};
+use std::ops::MulAssign;
+use std::sync::Arc;
+
+use ff::{Field, PrimeField};
+use group::{CurveAffine, CurveProjective, Group};
+use pairing::{CurveParameters, Engine, PairingCurveAffine};
+use rand_core::{CryptoRng, RngCore};
+
+use super::{Parameters, VerifyingKey};
+use crate::SynthesisError;
+
+/// A transcript hash binding a contribution to the exact `Parameters` it was computed
+/// over, so that a verifier can check a chain of contributions without trusting any
+/// single participant.
+pub type ContributionHash = [u8; 64];
+
+/// Groth16 `Parameters` mid-way through a phase-2 MPC ceremony, together with the
+/// transcript of public keys contributed so far.
+#[derive(Clone)]
+pub struct MPCParameters<E: Engine> {
+    pub params: Parameters<E>,
+    contributions: Vec<PublicKey<E>>,
+}
+
+/// The public portion of one participant's contribution, used by [`MPCParameters::verify`]
+/// to check that `delta` was updated consistently without learning the participant's
+/// private randomness.
+#[derive(Clone)]
+struct PublicKey<E: Engine> {
+    /// `delta_g1` immediately before this contribution, i.e. the previous link's
+    /// `delta_after_g1` (or the generator, for the first contribution).
+    delta_before_g1: E::G1Affine,
+    /// `delta_g1` immediately after this contribution.
+    delta_after_g1: E::G1Affine,
+    /// `r`, a nothing-up-my-sleeve G1 point derived from the transcript hash.
+    s: E::G1Affine,
+    /// `r * delta`, where `delta` is this contribution's private multiplier.
+    s_delta: E::G1Affine,
+    /// `delta_new` in G2, i.e. `delta_before_g2 * delta`.
+    r_delta: E::G2Affine,
+    /// The transcript hash of the `Parameters` as they stand after this contribution.
+    transcript: ContributionHash,
+}
+
+impl<E: Engine> MPCParameters<E> {
+    /// Begins a ceremony from freshly-generated phase-1 `Parameters`.
+    pub fn new(params: Parameters<E>) -> Self {
+        MPCParameters {
+            params,
+            contributions: vec![],
+        }
+    }
+
+    /// Contributes fresh private randomness, multiplying `delta` by it and updating the
+    /// `h`, `l`, `delta_g1` and `delta_g2` components of `self.params` to match. Returns
+    /// the transcript hash of the resulting parameters, which the participant should
+    /// publish alongside their contribution.
+    pub fn contribute<R: RngCore + CryptoRng>(&mut self, rng: &mut R) -> ContributionHash
+    where
+        E::G1Affine: CurveParameters,
+    {
+        let delta: E::Fr = E::Fr::random(rng);
+        let delta_inv = delta.invert().expect("nonzero with overwhelming probability");
+
+        // h and l are each divided by delta in the CRS, so multiplying delta by `delta`
+        // divides them by `delta` again: scale by `delta_inv`.
+        let h = Arc::get_mut(&mut self.params.h).expect("no other references to h should exist");
+        let l = Arc::get_mut(&mut self.params.l).expect("no other references to l should exist");
+        batch_scale::<E>(h, delta_inv);
+        batch_scale::<E>(l, delta_inv);
+
+        let delta_before_g1 = self.params.vk.delta_g1;
+        self.params.vk.delta_g1 = (self.params.vk.delta_g1 * delta).to_affine();
+        self.params.vk.delta_g2 = (self.params.vk.delta_g2 * delta).to_affine();
+
+        let transcript = self.transcript();
+
+        // `r` binds this contribution's public key to the exact parameters it
+        // produced, so a verifier can detect a participant reusing a public key
+        // across different (rolled-back) parameter states.
+        let r = hash_to_g1::<E>(&transcript);
+        let s = r.to_affine();
+        let s_delta = (r * delta).to_affine();
+
+        self.contributions.push(PublicKey {
+            delta_before_g1,
+            delta_after_g1: self.params.vk.delta_g1,
+            s,
+            s_delta,
+            r_delta: self.params.vk.delta_g2,
+            transcript,
+        });
+
+        transcript
+    }
+
+    /// Verifies that every contribution in the chain updated `delta` consistently: that
+    /// each link's `delta_before_g1` matches the previous link's `delta_after_g1` (the
+    /// first link must start from the generator, phase-1's untouched `delta`), that
+    /// `e(s, delta_new_g2) = e(s_delta, delta_before_g2)` holds for each contribution's
+    /// public key (proving the same multiplier relates both the G1 and G2 updates), and
+    /// that the final `delta_after_g1` matches the CRS's current `delta_g1`. On success,
+    /// returns the transcript hash of each contribution in order, which the caller can
+    /// compare against the participants' public announcements.
+    pub fn verify(&self) -> Result<Vec<ContributionHash>, SynthesisError>
+    where
+        E::G1Affine: CurveParameters,
+    {
+        let mut current_delta_g1 = E::G1Affine::generator();
+        let mut current_delta_g2 = E::G2Affine::generator();
+        let mut hashes = Vec::with_capacity(self.contributions.len());
+
+        for pubkey in &self.contributions {
+            if pubkey.delta_before_g1 != current_delta_g1 {
+                return Err(SynthesisError::MalformedCrs);
+            }
+
+            let expected_r = hash_to_g1::<E>(&pubkey.transcript).to_affine();
+            if expected_r != pubkey.s {
+                return Err(SynthesisError::MalformedCrs);
+            }
+
+            // e(s, delta_new_g2) == e(s_delta, delta_before_g2)
+            if E::pairing(pubkey.s, pubkey.r_delta) != E::pairing(pubkey.s_delta, current_delta_g2) {
+                return Err(SynthesisError::MalformedCrs);
+            }
+
+            current_delta_g1 = pubkey.delta_after_g1;
+            current_delta_g2 = pubkey.r_delta;
+            hashes.push(pubkey.transcript);
+        }
+
+        if current_delta_g1 == self.params.vk.delta_g1 {
+            Ok(hashes)
+        } else {
+            Err(SynthesisError::MalformedCrs)
+        }
+    }
+
+    /// Computes the transcript hash of the current parameters, as used by `contribute`
+    /// and `verify` to bind a contribution to an exact CRS state.
+    fn transcript(&self) -> ContributionHash {
+        let mut bytes = vec![];
+        self.params
+            .write(&mut bytes)
+            .expect("writing to a Vec cannot fail");
+
+        let mut transcript = super::transcript::Transcript::new(b"bellman phase2");
+        transcript.append_message(b"params", &bytes);
+        transcript.challenge_bytes(b"transcript")
+    }
+
+    pub fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        self.params.write(&mut writer)?;
+        writer.write_all(&(self.contributions.len() as u32).to_be_bytes())?;
+        for pubkey in &self.contributions {
+            writer.write_all(pubkey.delta_before_g1.to_uncompressed().as_ref())?;
+            writer.write_all(pubkey.delta_after_g1.to_uncompressed().as_ref())?;
+            writer.write_all(pubkey.s.to_uncompressed().as_ref())?;
+            writer.write_all(pubkey.s_delta.to_uncompressed().as_ref())?;
+            writer.write_all(pubkey.r_delta.to_uncompressed().as_ref())?;
+            writer.write_all(&pubkey.transcript)?;
+        }
+        Ok(())
+    }
+}
+
+fn batch_scale<E: Engine>(points: &mut [E::G1Affine], scalar: E::Fr) {
+    let mut projective: Vec<E::G1> = points.iter().map(|p| p.to_curve()).collect();
+    for p in &mut projective {
+        *p = *p * scalar;
+    }
+    let mut scaled = vec![E::G1Affine::from(E::G1::identity()); points.len()];
+    E::G1::batch_normalize(&projective, &mut scaled);
+    points.copy_from_slice(&scaled);
+}
+
+/// Hashes a transcript into a deterministic `G1` element, used to bind a contribution's
+/// public key to the parameters it was computed over (Schnorr-style "hash to a random
+/// base" proof of knowledge).
+///
+/// A real try-and-increment: each iteration derives only an `x`-coordinate candidate (via
+/// [`Transcript::challenge_scalar`](super::transcript::Transcript::challenge_scalar)) and
+/// recovers `y` as `sqrt(x^3 + b)`, retrying only on the roughly half of `x` values for
+/// which `x^3 + b` is a non-residue; a second challenge fixes which of the two square
+/// roots `y` should be, so a given `x` always maps to the same point. Earlier code here
+/// instead hashed a whole uncompressed `(x, y)` encoding, so both coordinates were
+/// independently random and the curve equation held with probability `~1/|Fq|` -- a loop
+/// that never terminates in practice.
+fn hash_to_g1<E: Engine>(transcript_hash: &ContributionHash) -> E::G1
+where
+    E::G1Affine: CurveParameters,
+{
+    let mut transcript = super::transcript::Transcript::new(b"bellman phase2 hash-to-g1");
+    transcript.append_message(b"transcript", transcript_hash);
+
+    let mut counter: u64 = 0;
+    loop {
+        let x: <E::G1Affine as CurveAffine>::Base = transcript.challenge_scalar(&counter.to_be_bytes());
+        let y_parity = transcript.challenge_bytes(&counter.to_be_bytes())[0] & 1 == 1;
+
+        let x3b = x.square() * x + <E::G1Affine as CurveParameters>::coeff_b();
+        if let Some(mut y) = Option::from(x3b.sqrt()) {
+            let y_is_odd = y.to_repr().as_ref()[0] & 1 == 1;
+            if y_is_odd != y_parity {
+                y = -y;
+            }
+
+            let candidate =
+                E::G1Affine::from_uncompressed_unchecked(&uncompressed_from_xy::<E>(&x, &y));
+            if candidate.is_some().into() {
+                let p = candidate.unwrap();
+                if !bool::from(p.is_identity()) {
+                    return p.to_curve();
+                }
+            }
+        }
+        counter += 1;
+    }
+}
+
+/// Assembles `x` and `y` into the big-endian `x || y` uncompressed encoding expected by
+/// [`CurveAffine::from_uncompressed_unchecked`], mirroring `bn256::G1Affine::to_uncompressed`'s
+/// layout (the only concrete curve this crate implements).
+fn uncompressed_from_xy<E: Engine>(
+    x: &<E::G1Affine as CurveAffine>::Base,
+    y: &<E::G1Affine as CurveAffine>::Base,
+) -> <E::G1Affine as CurveAffine>::Uncompressed
+where
+    E::G1Affine: CurveParameters,
+{
+    let mut repr = <E::G1Affine as CurveAffine>::Uncompressed::default();
+    let bytes = repr.as_mut();
+    let half = bytes.len() / 2;
+
+    let mut x_repr = x.to_repr();
+    x_repr.as_mut().reverse();
+    let mut y_repr = y.to_repr();
+    y_repr.as_mut().reverse();
+
+    bytes[..half].copy_from_slice(x_repr.as_ref());
+    bytes[half..].copy_from_slice(y_repr.as_ref());
+    repr
+}
+
@@ -0,0 +1,68 @@
+//! A small Blake2b-based Fiat-Shamir transcript.
+//!
+//! The phase-2 MPC ceremony ([`super::mpc`]) needs to derive challenges (the `hash to G1`
+//! used to bind a contribution's public key to the exact parameters it was computed over)
+//! deterministically from everything a participant has committed to so far. Hashing ad
+//! hoc byte concatenations is easy to get subtly wrong (e.g. `hash(a || b)` colliding with
+//! `hash(ab)` for differently-split inputs); [`Transcript`] instead domain-separates every
+//! append with a label and its length, so distinct protocol messages can never collide.
+
+use blake2b_simd::{Params, State};
+use ff::PrimeField;
+
+/// A append-only, domain-separated Blake2b transcript used to derive deterministic
+/// challenges from the sequence of messages absorbed so far.
+pub struct Transcript(State);
+
+impl Transcript {
+    /// Starts a new transcript labelled `protocol`, so transcripts for unrelated
+    /// protocols can never be confused with one another.
+    pub fn new(protocol: &[u8]) -> Self {
+        let mut state = Params::new().hash_length(64).to_state();
+        state.update(&(protocol.len() as u64).to_be_bytes());
+        state.update(protocol);
+        Transcript(state)
+    }
+
+    /// Absorbs `message` into the transcript under `label`.
+    pub fn append_message(&mut self, label: &[u8], message: &[u8]) {
+        self.0.update(&(label.len() as u64).to_be_bytes());
+        self.0.update(label);
+        self.0.update(&(message.len() as u64).to_be_bytes());
+        self.0.update(message);
+    }
+
+    /// Derives a 64-byte challenge from everything absorbed so far, without consuming the
+    /// transcript: further messages can still be appended afterwards.
+    pub fn challenge_bytes(&self, label: &[u8]) -> [u8; 64] {
+        let mut state = self.0.clone();
+        state.update(&(label.len() as u64).to_be_bytes());
+        state.update(label);
+        let mut out = [0u8; 64];
+        out.copy_from_slice(state.finalize().as_bytes());
+        out
+    }
+
+    /// Derives a challenge scalar by rejection-sampling [`Self::challenge_bytes`] against
+    /// `F`'s modulus, re-deriving with an incrementing counter appended to `label` on
+    /// rejection.
+    pub fn challenge_scalar<F: PrimeField>(&self, label: &[u8]) -> F {
+        let mut counter: u64 = 0;
+        loop {
+            let mut state = self.0.clone();
+            state.update(&(label.len() as u64).to_be_bytes());
+            state.update(label);
+            state.update(&counter.to_be_bytes());
+            let digest = state.finalize();
+
+            let mut repr = F::Repr::default();
+            let len = repr.as_mut().len().min(digest.as_bytes().len());
+            repr.as_mut()[..len].copy_from_slice(&digest.as_bytes()[..len]);
+
+            if let Some(scalar) = F::from_repr(repr).into() {
+                return scalar;
+            }
+            counter += 1;
+        }
+    }
+}
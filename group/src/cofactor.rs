@@ -1,9 +1,9 @@
-use core::fmt;
-use core::ops::{Mul, Neg};
-use ff::{BitIterator, Endianness, PrimeField};
+use core::ops::Mul;
+use ff::{BitIterator, Endianness, Field, PrimeField};
+use rand_core::RngCore;
 use subtle::{Choice, CtOption};
 
-use crate::{prime::PrimeGroup, Curve, Group, GroupEncoding, GroupOps, GroupOpsOwned};
+use crate::{prime::PrimeGroup, Curve, CurveAffine, Group, GroupEncoding, GroupOps, GroupOpsOwned};
 
 /// This trait represents an element of a cryptographic group with a large prime-order
 /// subgroup and a comparatively-small cofactor.
@@ -22,6 +22,26 @@ pub trait CofactorGroup:
     /// If `Self` implements [`PrimeGroup`], this returns `self`.
     fn mul_by_cofactor(&self) -> Self::Subgroup;
 
+    /// Multiplies `self` by the cofactor `h`, same as
+    /// [`mul_by_cofactor`](CofactorGroup::mul_by_cofactor) but returning `Self` rather than
+    /// converting into [`Subgroup`](CofactorGroup::Subgroup). Useful when further arithmetic
+    /// in `Self` follows before the eventual conversion, so the `Into<Self>` conversion only
+    /// happens once, at the end.
+    ///
+    /// The default implementation is just `mul_by_cofactor` composed with that conversion;
+    /// curves with a cheaper direct cofactor-clearing formula in `Self` can override it.
+    fn clear_cofactor(&self) -> Self {
+        self.mul_by_cofactor().into()
+    }
+
+    /// Lifts `s` into `Self` as `[h^{-1} mod q] * s`, where `h` is the cofactor and `q` is
+    /// the subgroup order: the canonical representative of `s` whose own cofactor-clearing
+    /// recovers it, i.e. `Self::mul_by_cofactor_inv(s).clear_cofactor() == (*s).into()`.
+    /// Paired with [`clear_cofactor`](CofactorGroup::clear_cofactor), this lets hash-to-curve
+    /// and encoding routines move a point between the cofactor group and its prime-order
+    /// subgroup deterministically in either direction.
+    fn mul_by_cofactor_inv(s: &Self::Subgroup) -> Self;
+
     /// Returns `self` if it is contained in the prime-order subgroup.
     ///
     /// If `Self` implements [`PrimeGroup`], this returns `Some(self)`.
@@ -62,6 +82,64 @@ pub trait CofactorGroup:
         // If the result is the identity, there was zero torsion component!
         res.is_identity()
     }
+
+    /// Checks whether every point in `points` lies in the prime-order subgroup, all at once.
+    ///
+    /// Calling [`is_torsion_free`](CofactorGroup::is_torsion_free) once per point costs one
+    /// full-width double-and-add per point. This instead draws a fresh random scalar `r_i`
+    /// for each point, forms the single random linear combination
+    /// `S = r_0 * points[0] + r_1 * points[1] + ...`, and checks `S.is_torsion_free()` --
+    /// one multiplication's worth of doublings total, rather than `n`. Each `points[i]`
+    /// decomposes as a subgroup component plus a torsion component; since the `r_i` are
+    /// independent and uniform, a nonzero torsion component surviving in every `points[i]`
+    /// cancels out of `S` only with probability `1/|Self::Scalar|`, so the aggregate check
+    /// rejects the batch except with negligible probability. Constant-time in the point
+    /// values: every point is scaled and accumulated regardless of where, or whether, a
+    /// torsion component appears.
+    fn batch_is_torsion_free<R: RngCore>(points: &[Self], rng: &mut R) -> Choice
+    where
+        Self: Mul<Self::Scalar, Output = Self>,
+    {
+        let mut acc = Self::identity();
+        for point in points {
+            let r = Self::Scalar::random(&mut *rng);
+            acc.add_assign(&(*point * r));
+        }
+        acc.is_torsion_free()
+    }
+}
+
+/// A [`CofactorGroup`] with an efficiently computable endomorphism ψ that acts as
+/// multiplication by a fixed, short eigenvalue `λ` on the prime-order subgroup (a GLV
+/// curve, e.g. the BLS12 groups or Jubjub).
+///
+/// [`CofactorGroup::is_torsion_free`]'s default implementation checks `[q] self ==
+/// identity`, a full `log2(q)`-bit double-and-add. When `self` lies in the prime-order
+/// subgroup, `ψ(self) == [λ] self` holds by definition of `λ`; when it doesn't, this
+/// equality fails except with negligible probability, since `ψ` acts as a different,
+/// independent eigenvalue on the torsion component. So [`Self::is_torsion_free_via_endomorphism`]
+/// gives the same answer using one application of `ψ` plus a multiplication by the much
+/// shorter `λ`, rather than a full-width one by `q`. Curves that implement this trait should
+/// override their [`CofactorGroup::is_torsion_free`] to call it; curves that don't fall back
+/// to the characteristic-based default.
+pub trait GlvGroup: CofactorGroup {
+    /// The eigenvalue `λ` such that `ψ` acts as `[λ]` on the prime-order subgroup.
+    const ENDOMORPHISM_EIGENVALUE: Self::Scalar;
+
+    /// The efficiently computable endomorphism ψ.
+    fn endomorphism(&self) -> Self;
+
+    /// Checks `ψ(self) == [λ] self` in constant time, using one application of `ψ` and one
+    /// short multiplication by [`ENDOMORPHISM_EIGENVALUE`](GlvGroup::ENDOMORPHISM_EIGENVALUE)
+    /// rather than [`is_torsion_free`](CofactorGroup::is_torsion_free)'s full-width one.
+    fn is_torsion_free_via_endomorphism(&self) -> Choice
+    where
+        Self: Mul<Self::Scalar, Output = Self> + Sized,
+    {
+        let mut diff = self.endomorphism();
+        diff.sub_assign(&(*self * Self::ENDOMORPHISM_EIGENVALUE));
+        diff.is_identity()
+    }
 }
 
 /// Efficient representation of an elliptic curve point guaranteed to be
@@ -76,38 +154,13 @@ pub trait CofactorCurve:
 
 /// Affine representation of an elliptic curve point guaranteed to be
 /// in the correct prime order subgroup.
-pub trait CofactorCurveAffine:
-    GroupEncoding
-    + Copy
-    + Clone
-    + Sized
-    + Send
-    + Sync
-    + fmt::Debug
-    + fmt::Display
-    + PartialEq
-    + Eq
-    + 'static
-    + Neg<Output = Self>
-    + Mul<<Self as CofactorCurveAffine>::Scalar, Output = <Self as CofactorCurveAffine>::Curve>
-    + for<'r> Mul<
-        <Self as CofactorCurveAffine>::Scalar,
-        Output = <Self as CofactorCurveAffine>::Curve,
-    >
-{
-    type Scalar: PrimeField;
+///
+/// This is a marker trait over [`CurveAffine`]: every coordinate, identity/generator,
+/// on-curve and (de)serialization method it needs is already inherited from there, so this
+/// trait only pins down the associated `Curve` type as a [`CofactorCurve`] rather than any
+/// `Curve`. (An earlier version of this trait duplicated `CurveAffine`'s whole method set
+/// as its own self-contained supertrait bound, rather than depending on `CurveAffine`
+/// directly -- reverted once `CurveAffine` existed in this crate to depend on.)
+pub trait CofactorCurveAffine: CurveAffine<Curve = <Self as CofactorCurveAffine>::Curve> {
     type Curve: CofactorCurve<Affine = Self, Scalar = Self::Scalar>;
-
-    /// Returns the additive identity.
-    fn identity() -> Self;
-
-    /// Returns a fixed generator of unknown exponent.
-    fn generator() -> Self;
-
-    /// Determines if this point represents the point at infinity; the
-    /// additive identity.
-    fn is_identity(&self) -> Choice;
-
-    /// Converts this element to its curve representation.
-    fn to_curve(&self) -> Self::Curve;
 }
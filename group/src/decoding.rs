@@ -0,0 +1,83 @@
+//! Typed errors for elliptic curve point deserialization.
+
+use core::fmt;
+
+/// Why a serialized elliptic curve point was rejected.
+///
+/// Plain `CtOption`-based decoding (e.g. `from_bytes`/`from_uncompressed`) only reports
+/// whether a point was accepted, so a caller can't tell a malformed encoding from a
+/// well-formed one that just isn't a valid point. A `*_checked` decoding function returning
+/// `Result<_, GroupDecodingError>` instead lets wallet and consensus code log, and react to,
+/// those cases differently.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GroupDecodingError {
+    /// The encoding's compression/infinity flag bits don't match what this decoding
+    /// function expects -- e.g. the compression flag was set while decoding an uncompressed
+    /// point, or vice versa.
+    UnexpectedCompressionMode,
+    /// The point is flagged as the identity, but also carries a sign/parity bit that is only
+    /// meaningful for a non-identity point.
+    UnexpectedInformation,
+    /// The point is flagged as the identity, but its coordinate bytes are nonzero.
+    NonCanonicalIdentity,
+    /// A coordinate's bytes decode to an integer greater than or equal to the field
+    /// characteristic.
+    CoordinateNotCanonical,
+    /// The coordinates don't satisfy the curve equation.
+    NotOnCurve,
+    /// The point lies on the curve but not in the prime-order subgroup.
+    NotInSubgroup,
+    /// The requested (de)serialization mode isn't implemented for this curve, e.g.
+    /// `Compress::Yes` where recovering a coordinate from the other requires a square root
+    /// this crate doesn't provide.
+    UnsupportedCompression,
+}
+
+impl fmt::Display for GroupDecodingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GroupDecodingError::UnexpectedCompressionMode => {
+                write!(f, "encoding has an unexpected compression mode")
+            }
+            GroupDecodingError::UnexpectedInformation => {
+                write!(f, "encoding has unexpected information for its compression mode")
+            }
+            GroupDecodingError::NonCanonicalIdentity => {
+                write!(f, "encoding of the identity is not the canonical one")
+            }
+            GroupDecodingError::CoordinateNotCanonical => write!(
+                f,
+                "coordinate is not canonically encoded (>= the field characteristic)"
+            ),
+            GroupDecodingError::NotOnCurve => write!(f, "point is not on the curve"),
+            GroupDecodingError::NotInSubgroup => {
+                write!(f, "point is not in the correct subgroup")
+            }
+            GroupDecodingError::UnsupportedCompression => {
+                write!(f, "this (de)serialization mode is not implemented for this curve")
+            }
+        }
+    }
+}
+
+/// Whether a `*_with_mode` (de)serialization call should use the shorter, sign-bit-based
+/// compressed point encoding or the full `x || y` uncompressed one. Named to match
+/// ark-serialize's mode-driven (de)serialization.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Compress {
+    Yes,
+    No,
+}
+
+/// Whether `deserialize_with_mode` should perform the prime-order subgroup check.
+///
+/// On-curve and coordinate-range checks always run regardless of this setting; only the
+/// subgroup check -- the dominant cost when ingesting many points, and the reason
+/// [`GroupDecodingError::NotInSubgroup`] exists -- is gated by it. Callers deserializing
+/// from an already-trusted source (their own previously-validated storage, say) can pass
+/// `Validate::No` to skip it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Validate {
+    Yes,
+    No,
+}
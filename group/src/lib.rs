@@ -0,0 +1,207 @@
+//! Traits for working with group elements, with an emphasis on elliptic curve groups as
+//! used in zero-knowledge proofs and related protocols.
+
+use core::fmt;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use ff::{Field, PrimeField};
+use rand_core::RngCore;
+use subtle::{Choice, CtOption};
+
+pub mod cofactor;
+pub mod decoding;
+pub mod prime;
+pub mod wnaf;
+
+pub use cofactor::{CofactorCurve, CofactorCurveAffine, CofactorGroup};
+pub use decoding::{Compress, GroupDecodingError, Validate};
+pub use wnaf::{wnaf_mul, Wnaf};
+
+/// A group usable as the output of a cryptographic group operation -- in this crate,
+/// primarily an elliptic curve group in either its affine or projective representation.
+pub trait Group:
+    Clone
+    + Copy
+    + fmt::Debug
+    + Eq
+    + Sized
+    + Send
+    + Sync
+    + 'static
+    + Neg<Output = Self>
+    + GroupOps
+    + GroupOpsOwned
+    + ScalarMul<Self::Scalar>
+    + ScalarMulOwned<Self::Scalar>
+{
+    /// The scalar field this group's points are multiplied by.
+    type Scalar: PrimeField;
+
+    /// Returns a random element of the group.
+    fn random(rng: impl RngCore) -> Self;
+
+    /// Returns the additive identity.
+    fn identity() -> Self;
+
+    /// Returns a fixed generator of unknown exponent.
+    fn generator() -> Self;
+
+    /// Determines if this point represents the point at infinity; the additive identity.
+    fn is_identity(&self) -> Choice;
+
+    /// Doubles this element.
+    #[must_use]
+    fn double(&self) -> Self;
+}
+
+/// A type with a canonical byte representation that round-trips through a group element.
+pub trait GroupEncoding: Sized {
+    /// The encoded byte representation.
+    type Repr: Default + AsRef<[u8]> + AsMut<[u8]>;
+
+    /// Deserializes this element from its encoding, failing if the encoding is malformed or
+    /// doesn't correspond to an element of the group.
+    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self>;
+
+    /// Deserializes this element from its encoding, without checking that it represents a
+    /// valid element of the group (only that it's well-formed). Faster than
+    /// [`GroupEncoding::from_bytes`] for callers that already trust the source.
+    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self>;
+
+    /// Converts this element into its byte encoding.
+    fn to_bytes(&self) -> Self::Repr;
+}
+
+/// A [`Group`] with an affine representation it can be converted to/from.
+pub trait Curve: Group + GroupOps<Self::AffineRepr> + GroupOpsOwned<Self::AffineRepr> {
+    /// The affine representation of this element.
+    type AffineRepr;
+
+    /// Converts this element into its affine representation.
+    fn to_affine(&self) -> Self::AffineRepr;
+
+    /// Converts a batch of projective elements into affine ones, one at a time.
+    ///
+    /// Curves with a cheaper batched inversion for this conversion should override this
+    /// default with one.
+    fn batch_normalize(p: &[Self], q: &mut [Self::AffineRepr]) {
+        assert_eq!(p.len(), q.len());
+        for (p, q) in p.iter().zip(q.iter_mut()) {
+            *q = p.to_affine();
+        }
+    }
+}
+
+/// Affine representation of an elliptic curve point: the canonical unifying trait that lets
+/// generic code -- e.g. [`cofactor::CofactorCurveAffine`] -- be written once against any
+/// curve's affine point type, rather than against each curve's own inherent methods.
+///
+/// Only this trait itself is defined here; no concrete curve in this workspace implements
+/// it yet (`bn256::G1Affine`/`G2Affine` still only expose their coordinate/encoding methods
+/// inherently). Wiring a concrete curve up to it -- and to the rest of the `Group`/`Curve`
+/// hierarchy -- is a separate, substantially larger undertaking than adding the trait.
+pub trait CurveAffine:
+    Copy
+    + Clone
+    + Sized
+    + Send
+    + Sync
+    + fmt::Debug
+    + fmt::Display
+    + PartialEq
+    + Eq
+    + 'static
+    + Neg<Output = Self>
+    + Mul<Self::Scalar, Output = Self::Curve>
+    + for<'r> Mul<Self::Scalar, Output = Self::Curve>
+    + GroupEncoding
+{
+    /// The scalar field this curve's points are multiplied by.
+    type Scalar: PrimeField;
+
+    /// The field each coordinate of this curve's points is drawn from.
+    type Base: Field;
+
+    /// The projective representation of this curve's points.
+    type Curve: Curve<AffineRepr = Self, Scalar = Self::Scalar>;
+
+    /// Returns the additive identity.
+    fn identity() -> Self;
+
+    /// Returns a fixed generator of unknown exponent.
+    fn generator() -> Self;
+
+    /// Determines if this point represents the point at infinity; the additive identity.
+    fn is_identity(&self) -> Choice;
+
+    /// Converts this element to its curve representation.
+    fn to_curve(&self) -> Self::Curve;
+
+    /// Returns this point's `x`-coordinate, or `None` for the point at infinity.
+    fn to_x_coordinate(&self) -> Option<Self::Base>;
+
+    /// Returns this point's `y`-coordinate, or `None` for the point at infinity.
+    fn to_y_coordinate(&self) -> Option<Self::Base>;
+
+    /// Determines if this point satisfies the curve equation.
+    ///
+    /// The point at infinity is always considered on-curve.
+    fn is_on_curve(&self) -> Choice;
+
+    /// Recovers the point with `x`-coordinate `x`, preferring the "greatest" (in the
+    /// curve's own, implementation-defined ordering -- typically by the parity of the
+    /// other coordinate) of its (up to) two `y`-coordinates when `greatest` is set,
+    /// otherwise preferring the "lesser" one. Returns `None` if `x` isn't the coordinate of
+    /// any point on the curve.
+    fn from_x_coordinate(x: Self::Base, greatest: Choice) -> CtOption<Self>;
+
+    /// Recovers the point with `y`-coordinate `y`, preferring the "greatest" of its (up to)
+    /// two `x`-coordinates when `greatest` is set, otherwise preferring the "lesser" one.
+    /// Returns `None` if `y` isn't the coordinate of any point on the curve.
+    fn from_y_coordinate(y: Self::Base, greatest: Choice) -> CtOption<Self>;
+}
+
+/// Elements supporting group addition and subtraction, owned and by reference, against
+/// `Rhs`, producing `Output`.
+pub trait GroupOps<Rhs = Self, Output = Self>:
+    Add<Rhs, Output = Output>
+    + Sub<Rhs, Output = Output>
+    + AddAssign<Rhs>
+    + SubAssign<Rhs>
+    + for<'r> Add<&'r Rhs, Output = Output>
+    + for<'r> Sub<&'r Rhs, Output = Output>
+    + for<'r> AddAssign<&'r Rhs>
+    + for<'r> SubAssign<&'r Rhs>
+{
+}
+
+impl<T, Rhs, Output> GroupOps<Rhs, Output> for T where
+    T: Add<Rhs, Output = Output>
+        + Sub<Rhs, Output = Output>
+        + AddAssign<Rhs>
+        + SubAssign<Rhs>
+        + for<'r> Add<&'r Rhs, Output = Output>
+        + for<'r> Sub<&'r Rhs, Output = Output>
+        + for<'r> AddAssign<&'r Rhs>
+        + for<'r> SubAssign<&'r Rhs>
+{
+}
+
+/// Same as [`GroupOps`], but for a `Rhs` always taken by reference.
+pub trait GroupOpsOwned<Rhs = Self, Output = Self>: for<'r> GroupOps<&'r Rhs, Output> {}
+impl<T, Rhs, Output> GroupOpsOwned<Rhs, Output> for T where T: for<'r> GroupOps<&'r Rhs, Output> {}
+
+/// Elements supporting scalar multiplication, owned and by reference, against `Rhs`,
+/// producing `Output`.
+pub trait ScalarMul<Rhs, Output = Self>:
+    Mul<Rhs, Output = Output> + MulAssign<Rhs> + for<'r> Mul<&'r Rhs, Output = Output>
+{
+}
+impl<T, Rhs, Output> ScalarMul<Rhs, Output> for T where
+    T: Mul<Rhs, Output = Output> + MulAssign<Rhs> + for<'r> Mul<&'r Rhs, Output = Output>
+{
+}
+
+/// Same as [`ScalarMul`], but for a `Rhs` always taken by reference.
+pub trait ScalarMulOwned<Rhs, Output = Self>: for<'r> ScalarMul<&'r Rhs, Output> {}
+impl<T, Rhs, Output> ScalarMulOwned<Rhs, Output> for T where T: for<'r> ScalarMul<&'r Rhs, Output> {}
@@ -0,0 +1,12 @@
+//! Prime-order groups, i.e. groups with no cofactor to worry about.
+
+use crate::{Group, GroupEncoding};
+
+/// A [`Group`] that is itself of prime order, with no cofactor.
+///
+/// [`cofactor::CofactorGroup::Subgroup`](crate::cofactor::CofactorGroup::Subgroup) is
+/// bounded by this trait: it's the prime-order subgroup that cofactor-clearing and
+/// subgroup-membership checks move points into and out of.
+pub trait PrimeGroup: Group + GroupEncoding {}
+
+impl<G: Group + GroupEncoding> PrimeGroup for G {}
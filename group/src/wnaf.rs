@@ -0,0 +1,238 @@
+//! Windowed non-adjacent form (wNAF) scalar multiplication.
+//!
+//! The only multiplication available elsewhere in this crate is the naive double-and-add
+//! performed by [`CofactorGroup::is_torsion_free`](crate::cofactor::CofactorGroup::is_torsion_free)
+//! and the blanket `Mul<Scalar>` bound on [`CofactorCurveAffine`](crate::cofactor::CofactorCurveAffine):
+//! one addition per set bit of the scalar, with the base's doublings recomputed from
+//! scratch on every call. [`Wnaf`] instead precomputes a table of `base`'s odd multiples
+//! once, converts each scalar into its width-`w` non-adjacent form, and evaluates the
+//! product scanning NAF digits MSB-first -- one addition per *nonzero* digit rather than
+//! per bit, at the cost of the table. [`Wnaf::base`] lets that cost be paid once and
+//! amortized across many scalars against the same fixed base; [`wnaf_mul`] covers the
+//! one-off case where there's no base to amortize it against.
+
+use ff::PrimeField;
+
+use crate::Group;
+
+/// Replaces `table` with `base`'s first `2^(window - 1)` odd multiples, in order:
+/// `[1] base, [3] base, [5] base, ..., [2^window - 1] base`.
+fn wnaf_table<G: Group>(table: &mut Vec<G>, base: G, window: usize) {
+    table.truncate(0);
+    table.reserve(1 << (window - 1));
+
+    let double = base.double();
+    let mut current = base;
+    for _ in 0..(1 << (window - 1)) {
+        table.push(current);
+        current.add_assign(&double);
+    }
+}
+
+/// Converts `scalar` into its width-`window` non-adjacent form and stores its digits in
+/// `wnaf`, LSB-first: each digit is either `0` or an odd integer in `(-2^window, 2^window)`,
+/// at least `window - 1` of the digits following a nonzero one are `0`, and `scalar` is
+/// recovered as `sum(wnaf[i] * 2^i)`.
+fn wnaf_form<S: PrimeField>(wnaf: &mut Vec<i64>, scalar: S, window: usize) {
+    assert!(window >= 2);
+    assert!(window + 1 < 64);
+
+    wnaf.truncate(0);
+
+    let mut repr = scalar.to_repr();
+    <S as PrimeField>::ReprEndianness::toggle_little_endian(&mut repr);
+
+    // One extra (zero) byte of headroom: clearing the low `window + 1` bits of a value
+    // whose own top bits are all `1` (e.g. reducing `0b0111_1111` to a single digit) carries
+    // one bit past the scalar's own width.
+    let mut digits = repr.as_ref().to_vec();
+    digits.push(0);
+
+    while !is_zero(&digits) {
+        let digit = if digits[0] & 1 == 1 {
+            let mut d = low_bits(&digits, window + 1) as i64;
+            if d > (1 << window) {
+                d -= 1 << (window + 1);
+            }
+            if d > 0 {
+                sub_small(&mut digits, d as u64);
+            } else {
+                add_small(&mut digits, (-d) as u64);
+            }
+            d
+        } else {
+            0
+        };
+
+        wnaf.push(digit);
+        div2(&mut digits);
+    }
+}
+
+/// Evaluates `sum(wnaf[i] * [2^i] base)` given `table`, the odd-multiples table
+/// [`wnaf_table`] built for `base`, and `wnaf`, the LSB-first digits [`wnaf_form`] produced
+/// for the scalar to multiply `base` by.
+fn wnaf_exp<G: Group>(table: &[G], wnaf: &[i64]) -> G {
+    let mut result = G::identity();
+    let mut found_one = false;
+
+    for &digit in wnaf.iter().rev() {
+        if found_one {
+            result = result.double();
+        }
+
+        if digit > 0 {
+            found_one = true;
+            result.add_assign(&table[(digit as usize - 1) / 2]);
+        } else if digit < 0 {
+            found_one = true;
+            result.sub_assign(&table[(-digit as usize - 1) / 2]);
+        }
+    }
+
+    result
+}
+
+/// A reusable wNAF table and scratch digit buffer.
+///
+/// Call [`Wnaf::base`] once per fixed base to precompute its table, sized for the number of
+/// scalars it will be multiplied by, then [`Wnaf::scalar`] once per scalar to evaluate the
+/// product against that table. Both the table and the scratch buffer are kept around and
+/// overwritten in place by later calls, so a single `Wnaf` can be reused across unrelated
+/// bases and scalars without reallocating.
+#[derive(Clone, Debug)]
+pub struct Wnaf<G: Group> {
+    table: Vec<G>,
+    digits: Vec<i64>,
+    window: usize,
+}
+
+impl<G: Group> Wnaf<G> {
+    /// Creates an empty `Wnaf` with no base set yet; [`Wnaf::base`] must be called before
+    /// [`Wnaf::scalar`].
+    pub fn new() -> Self {
+        Wnaf {
+            table: vec![],
+            digits: vec![],
+            window: 2,
+        }
+    }
+
+    /// Precomputes `base`'s odd-multiples table, sized for `num_scalars` subsequent
+    /// [`Wnaf::scalar`] calls against it.
+    pub fn base(&mut self, base: G, num_scalars: usize) -> &mut Self {
+        self.window = Self::window_size(num_scalars);
+        wnaf_table(&mut self.table, base, self.window);
+        self
+    }
+
+    /// Returns `[scalar] base`, where `base` is the one most recently passed to
+    /// [`Wnaf::base`].
+    pub fn scalar(&mut self, scalar: &G::Scalar) -> G {
+        wnaf_form(&mut self.digits, *scalar, self.window);
+        wnaf_exp(&self.table, &self.digits)
+    }
+
+    /// Picks a window width for a fixed base that will be multiplied by `num_scalars`
+    /// distinct scalars: each extra bit of width roughly halves the number of additions
+    /// needed per scalar, at the cost of doubling the table, so a wider window only pays for
+    /// itself once its one-time cost is spread over enough scalars.
+    fn window_size(num_scalars: usize) -> usize {
+        // The number of scalars above which each window width starts paying for itself,
+        // empirically; index `i` is the threshold for window width `i + 3`.
+        const RECOMMENDATIONS: [usize; 22] = [
+            1, 3, 7, 20, 43, 120, 273, 563, 1630, 3336, 7032, 14245, 27898, 57122, 118214,
+            253647, 500000, 830000, 1420000, 2580000, 5000000, 8000000,
+        ];
+
+        let mut window = 0;
+        while window + 1 < RECOMMENDATIONS.len() && num_scalars > RECOMMENDATIONS[window] {
+            window += 1;
+        }
+        window + 2
+    }
+}
+
+impl<G: Group> Default for Wnaf<G> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns `[scalar] base`, building a table sized for this single multiplication.
+///
+/// Prefer [`Wnaf::base`] and [`Wnaf::scalar`] directly when `base` will be multiplied by
+/// more than one scalar, so its table is computed once and reused.
+pub fn wnaf_mul<G: Group>(base: G, scalar: &G::Scalar) -> G {
+    let mut wnaf = Wnaf::new();
+    wnaf.base(base, 1);
+    wnaf.scalar(scalar)
+}
+
+fn is_zero(bytes: &[u8]) -> bool {
+    bytes.iter().all(|&b| b == 0)
+}
+
+fn low_bits(bytes: &[u8], bits: usize) -> u64 {
+    debug_assert!(bits <= 64);
+
+    let mut acc = 0u64;
+    let mut shift = 0;
+    for &b in bytes {
+        if shift >= bits {
+            break;
+        }
+        acc |= (b as u64) << shift;
+        shift += 8;
+    }
+    if bits == 64 {
+        acc
+    } else {
+        acc & ((1u64 << bits) - 1)
+    }
+}
+
+fn sub_small(bytes: &mut [u8], mut value: u64) {
+    let mut borrow = 0i64;
+    for b in bytes.iter_mut() {
+        let piece = (value & 0xff) as i64;
+        value >>= 8;
+
+        let mut total = *b as i64 - piece - borrow;
+        borrow = 0;
+        if total < 0 {
+            total += 256;
+            borrow = 1;
+        }
+        *b = total as u8;
+
+        if value == 0 && borrow == 0 {
+            break;
+        }
+    }
+}
+
+fn add_small(bytes: &mut [u8], mut value: u64) {
+    let mut carry = 0u64;
+    for b in bytes.iter_mut() {
+        let piece = value & 0xff;
+        value >>= 8;
+
+        let total = *b as u64 + piece + carry;
+        *b = (total & 0xff) as u8;
+        carry = total >> 8;
+
+        if value == 0 && carry == 0 {
+            break;
+        }
+    }
+}
+
+fn div2(bytes: &mut [u8]) {
+    let mut carry = 0u8;
+    for b in bytes.iter_mut().rev() {
+        let next_carry = *b & 1;
+        *b = (*b >> 1) | (carry << 7);
+        carry = next_carry;
+    }
+}
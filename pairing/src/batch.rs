@@ -0,0 +1,83 @@
+//! Randomized batch verification of independent pairing equations.
+
+use ff::Field;
+use rand_core::RngCore;
+
+use crate::{Engine, MillerLoopResult, MultiMillerLoop, PairingCurveAffine};
+
+/// Verifies a batch of independent pairing equations `e(A_i, B_i) = 1` using a single
+/// random linear combination, rather than one final exponentiation per equation.
+///
+/// Each pushed term scales its `G1` input by a freshly-sampled nonzero scalar before
+/// accumulating it into a single multi-Miller loop; the whole batch is accepted only if
+/// the resulting product collapses to the identity of `Gt`. A forged equation is caught
+/// with probability `1 - 1/|Fr|`, since an adversary cannot predict the random scalars
+/// used to combine it with the other terms.
+#[derive(Debug)]
+pub struct PairingBatch<E: MultiMillerLoop> {
+    terms: Vec<(E::G1Affine, <E::G2Affine as PairingCurveAffine>::Prepared)>,
+}
+
+impl<E: MultiMillerLoop> Default for PairingBatch<E> {
+    fn default() -> Self {
+        PairingBatch { terms: Vec::new() }
+    }
+}
+
+impl<E: MultiMillerLoop> PairingBatch<E> {
+    /// Creates an empty batch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues `(g1, g2)` as a term of the equation `e(g1, g2) = 1` to be checked.
+    ///
+    /// `g2` should already be [`PairingCurveAffine::prepare`]d, so that a fixed
+    /// verification key only pays the preparation cost once across many `verify` calls.
+    pub fn add(
+        &mut self,
+        g1: E::G1Affine,
+        g2: <E::G2Affine as PairingCurveAffine>::Prepared,
+    ) -> &mut Self {
+        self.terms.push((g1, g2));
+        self
+    }
+
+    /// Verifies every queued equation at once, returning `true` only if all of them hold.
+    pub fn verify<R: RngCore>(&self, mut rng: R) -> bool {
+        if self.terms.is_empty() {
+            return true;
+        }
+
+        let scaled: Vec<(E::G1Affine, &<E::G2Affine as PairingCurveAffine>::Prepared)> = self
+            .terms
+            .iter()
+            .map(|(g1, g2)| {
+                let r = loop {
+                    let r = E::Fr::random(&mut rng);
+                    if !bool::from(r.is_zero()) {
+                        break r;
+                    }
+                };
+                ((*g1 * r).into(), g2)
+            })
+            .collect();
+
+        let refs: Vec<_> = scaled.iter().map(|(g1, g2)| (g1, *g2)).collect();
+        let acc = E::multi_miller_loop(&refs);
+
+        bool::from(acc.final_exponentiation().ct_eq_identity())
+    }
+}
+
+/// Helper for comparing a `Gt` element against the identity without requiring callers of
+/// [`PairingBatch::verify`] to import `group::Group` themselves.
+trait IsIdentity {
+    fn ct_eq_identity(&self) -> subtle::Choice;
+}
+
+impl<G: group::Group> IsIdentity for G {
+    fn ct_eq_identity(&self) -> subtle::Choice {
+        self.is_identity()
+    }
+}
@@ -0,0 +1,38 @@
+//! Low-level arithmetic backend for the BN254 engine.
+//!
+//! [`Bn256`](super::Bn256)'s public `Engine`/`PairingCurveAffine` surface is implemented
+//! once, delegating its Miller loop to a [`Backend`]. Only [`PureRust`] exists today;
+//! [`Backend`] is kept as the seam a real hand-optimized backend (assembly or SIMD base
+//! field arithmetic, mirroring how `blstrs` layers one underneath the same `bls12_381`
+//! API) would plug into, rather than something downstream crates need to name directly.
+use super::fq12::Fq12;
+use super::g1::G1Affine;
+use super::g2::G2Prepared;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A low-level implementation of the BN254 Miller loop, selected at compile time.
+///
+/// This trait is sealed: it exists only to let [`Bn256`](super::Bn256) swap its
+/// arithmetic implementation without perturbing the public `Engine` associated types or
+/// method signatures.
+pub trait Backend: private::Sealed {
+    /// Runs the Miller loop over `terms`, as used by `Engine::miller_loop`.
+    fn miller_loop(terms: &[(&G1Affine, &G2Prepared)]) -> Fq12;
+}
+
+/// The only backend implemented so far: portable, pure-Rust field arithmetic.
+#[derive(Clone, Copy, Debug)]
+pub struct PureRust;
+
+impl private::Sealed for PureRust {}
+
+impl Backend for PureRust {
+    fn miller_loop(terms: &[(&G1Affine, &G2Prepared)]) -> Fq12 {
+        super::miller_loop_pure_rust(terms)
+    }
+}
+
+pub(crate) type ActiveBackend = PureRust;
@@ -0,0 +1,20 @@
+use ff::PrimeField;
+
+/// An element of `GF(p)` where
+/// `p = 21888242871839275222246405745257275088696311157297823662689037894645226208583`.
+///
+/// This is the base field of the BN254 (a.k.a. `alt_bn128`) curve, over which both `G1`
+/// and the sextic twist underlying `G2` are defined.
+#[derive(PrimeField)]
+#[PrimeFieldModulus = "21888242871839275222246405745257275088696311157297823662689037894645226208583"]
+#[PrimeFieldGenerator = "3"]
+#[PrimeFieldReprEndianness = "little"]
+pub struct Fq([u64; 4]);
+
+/// The scalar field of the BN254 curve, of prime order
+/// `r = 21888242871839275222246405745257275088548364400416034343698204186575808495617`.
+#[derive(PrimeField)]
+#[PrimeFieldModulus = "21888242871839275222246405745257275088548364400416034343698204186575808495617"]
+#[PrimeFieldGenerator = "5"]
+#[PrimeFieldReprEndianness = "little"]
+pub struct Fr([u64; 4]);
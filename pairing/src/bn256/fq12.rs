@@ -0,0 +1,201 @@
+use core::ops::{Add, Mul, Neg, Sub};
+
+use ff::Field;
+use rand_core::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+use super::fq6::{self, Fq6};
+
+/// An element of `GF(p^12) = GF(p^6)[w] / (w^2 - v)`.
+///
+/// This is the field that hosts the target group of the BN254 pairing: the result of
+/// `miller_loop` lives here before the final exponentiation projects it into the
+/// order-`r` cyclotomic subgroup exposed as [`super::Gt`].
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Fq12 {
+    pub c0: Fq6,
+    pub c1: Fq6,
+}
+
+impl ConditionallySelectable for Fq12 {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Fq12 {
+            c0: Fq6::conditional_select(&a.c0, &b.c0, choice),
+            c1: Fq6::conditional_select(&a.c1, &b.c1, choice),
+        }
+    }
+}
+
+impl ConstantTimeEq for Fq12 {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.c0.ct_eq(&other.c0) & self.c1.ct_eq(&other.c1)
+    }
+}
+
+impl Eq for Fq12 {}
+impl PartialEq for Fq12 {
+    fn eq(&self, other: &Self) -> bool {
+        bool::from(self.ct_eq(other))
+    }
+}
+
+impl Fq12 {
+    /// Returns the conjugate of this element with respect to the quadratic subextension,
+    /// i.e. negates the `w` component, which computes `x^(p^6)`. The final exponentiation's
+    /// easy part multiplies it by a full inverse, since Miller loop outputs are not unitary;
+    /// on unitary elements (`x * conjugate(x) = 1`), such as those of `Gt`, it is the inverse.
+    pub fn conjugate(&self) -> Self {
+        Fq12 {
+            c0: self.c0,
+            c1: -self.c1,
+        }
+    }
+
+    /// Multiplication restricted to the sparse line-function shape produced by the
+    /// Miller loop, `(c0, 0, 0) + (c1, c4, 0) * w` in `Fq6` coordinates.
+    /// Used by the pairing's Miller loop to avoid full dense `Fq12` multiplications.
+    pub fn mul_by_014(&self, c0: super::fq2::Fq2, c1: super::fq2::Fq2, c4: super::fq2::Fq2) -> Self {
+        let aa = Fq6 {
+            c0: self.c0.c0 * c0,
+            c1: self.c0.c1 * c0,
+            c2: self.c0.c2 * c0,
+        };
+        let bb = self.c1 * Fq6 {
+            c0: c1,
+            c1: c4,
+            c2: super::fq2::Fq2::zero(),
+        };
+        let o = c0 + c1;
+        let c1_new = (self.c0 + self.c1) * Fq6 {
+            c0: o,
+            c1: c4,
+            c2: super::fq2::Fq2::zero(),
+        } - aa
+            - bb;
+        let c0_new = bb.mul_by_nonresidue() + aa;
+        Fq12 {
+            c0: c0_new,
+            c1: c1_new,
+        }
+    }
+
+    /// Applies the degree-2 Frobenius endomorphism `x -> x^(p^2)` to this element. Used by
+    /// the BN254 final exponentiation's "easy part" (see `bn256::MillerLoopResult`), which
+    /// needs `x^(p^2)` for `x` in the pairing target group.
+    ///
+    /// `self.c0`'s `x^(p^2)` is [`Fq6::frobenius_map2`]; `self.c1`'s additionally picks up
+    /// `w^(p^2) = \gamma_3`, since `w^2 = v` and `w` is `Fq12`'s own generator over `Fq6`.
+    pub(crate) fn frobenius_map2(&self) -> Self {
+        Fq12 {
+            c0: self.c0.frobenius_map2(),
+            c1: self.c1.frobenius_map2().scale_by_fq2(fq6::frobenius_gamma3_2()),
+        }
+    }
+}
+
+impl Add for Fq12 {
+    type Output = Fq12;
+
+    fn add(self, rhs: Fq12) -> Fq12 {
+        Fq12 {
+            c0: self.c0 + rhs.c0,
+            c1: self.c1 + rhs.c1,
+        }
+    }
+}
+
+impl Sub for Fq12 {
+    type Output = Fq12;
+
+    fn sub(self, rhs: Fq12) -> Fq12 {
+        Fq12 {
+            c0: self.c0 - rhs.c0,
+            c1: self.c1 - rhs.c1,
+        }
+    }
+}
+
+impl Neg for Fq12 {
+    type Output = Fq12;
+
+    fn neg(self) -> Fq12 {
+        Fq12 {
+            c0: -self.c0,
+            c1: -self.c1,
+        }
+    }
+}
+
+impl Mul for Fq12 {
+    type Output = Fq12;
+
+    fn mul(self, rhs: Fq12) -> Fq12 {
+        let aa = self.c0 * rhs.c0;
+        let bb = self.c1 * rhs.c1;
+        let c0 = bb.mul_by_nonresidue() + aa;
+        let c1 = (self.c0 + self.c1) * (rhs.c0 + rhs.c1) - aa - bb;
+        Fq12 { c0, c1 }
+    }
+}
+
+impl Field for Fq12 {
+    fn random(mut rng: impl RngCore) -> Self {
+        Fq12 {
+            c0: Fq6::random(&mut rng),
+            c1: Fq6::random(&mut rng),
+        }
+    }
+
+    fn zero() -> Self {
+        Fq12 {
+            c0: Fq6::zero(),
+            c1: Fq6::zero(),
+        }
+    }
+
+    fn one() -> Self {
+        Fq12 {
+            c0: Fq6::one(),
+            c1: Fq6::zero(),
+        }
+    }
+
+    fn is_zero(&self) -> Choice {
+        self.c0.is_zero() & self.c1.is_zero()
+    }
+
+    #[must_use]
+    fn square(&self) -> Self {
+        // (c0 + c1*w)^2 = (c0^2 + \xi_6*c1^2) + 2*c0*c1*w, where \xi_6 = v is the
+        // non-residue `Fq6::mul_by_nonresidue` multiplies by. Computed via the usual
+        // complex-squaring trick: (c0+c1)*(c0 + \xi_6*c1) - c0*c1 - \xi_6*c0*c1
+        // expands to c0^2 + \xi_6*c0*c1 + c0*c1 + \xi_6*c1^2 - c0*c1 - \xi_6*c0*c1
+        // = c0^2 + \xi_6*c1^2.
+        let ab = self.c0 * self.c1;
+        let c0 = (self.c0 + self.c1) * (self.c0 + self.c1.mul_by_nonresidue()) - ab
+            - ab.mul_by_nonresidue();
+        let c1 = ab.double();
+        Fq12 { c0, c1 }
+    }
+
+    #[must_use]
+    fn double(&self) -> Self {
+        Fq12 {
+            c0: self.c0.double(),
+            c1: self.c1.double(),
+        }
+    }
+
+    fn invert(&self) -> CtOption<Self> {
+        (self.c0.square() - self.c1.square().mul_by_nonresidue())
+            .invert()
+            .map(|t| Fq12 {
+                c0: self.c0 * t,
+                c1: (self.c1 * t).neg(),
+            })
+    }
+
+    fn sqrt(&self) -> CtOption<Self> {
+        CtOption::new(Self::zero(), Choice::from(0))
+    }
+}
@@ -0,0 +1,202 @@
+use core::ops::{Add, Mul, Neg, Sub};
+
+use ff::Field;
+use rand_core::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+use super::fq::Fq;
+
+/// An element of `GF(p^2) = GF(p)[u] / (u^2 + 1)`, the quadratic extension field used as
+/// the base field for `G2` and as a building block for `Fq6`/`Fq12`.
+///
+/// `u` is chosen so that `u^2 = -1`, i.e. `-1` is a quadratic non-residue in `Fq`.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Fq2 {
+    pub c0: Fq,
+    pub c1: Fq,
+}
+
+impl ConditionallySelectable for Fq2 {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Fq2 {
+            c0: Fq::conditional_select(&a.c0, &b.c0, choice),
+            c1: Fq::conditional_select(&a.c1, &b.c1, choice),
+        }
+    }
+}
+
+impl ConstantTimeEq for Fq2 {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.c0.ct_eq(&other.c0) & self.c1.ct_eq(&other.c1)
+    }
+}
+
+impl Eq for Fq2 {}
+impl PartialEq for Fq2 {
+    fn eq(&self, other: &Self) -> bool {
+        bool::from(self.ct_eq(other))
+    }
+}
+
+impl Fq2 {
+    /// Returns the complex conjugate of this element, i.e. negates the `u` component.
+    pub fn conjugate(&self) -> Self {
+        Fq2 {
+            c0: self.c0,
+            c1: -self.c1,
+        }
+    }
+
+    /// Multiplies this element by the non-residue `\xi = (9, 1)` used to build `Fq6`.
+    pub fn mul_by_nonresidue(&self) -> Self {
+        // (c0 + c1*u) * (9 + u) = (9*c0 - c1) + (c0 + 9*c1)*u
+        let t0 = self.c0 * Fq::from(9u64) - self.c1;
+        let t1 = self.c0 + self.c1 * Fq::from(9u64);
+        Fq2 { c0: t0, c1: t1 }
+    }
+
+    fn norm(&self) -> Fq {
+        // N(c0 + c1*u) = c0^2 + c1^2, since u^2 = -1.
+        self.c0.square() + self.c1.square()
+    }
+}
+
+impl Add for Fq2 {
+    type Output = Fq2;
+
+    fn add(self, rhs: Fq2) -> Fq2 {
+        Fq2 {
+            c0: self.c0 + rhs.c0,
+            c1: self.c1 + rhs.c1,
+        }
+    }
+}
+
+impl Sub for Fq2 {
+    type Output = Fq2;
+
+    fn sub(self, rhs: Fq2) -> Fq2 {
+        Fq2 {
+            c0: self.c0 - rhs.c0,
+            c1: self.c1 - rhs.c1,
+        }
+    }
+}
+
+impl Neg for Fq2 {
+    type Output = Fq2;
+
+    fn neg(self) -> Fq2 {
+        Fq2 {
+            c0: -self.c0,
+            c1: -self.c1,
+        }
+    }
+}
+
+impl Mul for Fq2 {
+    type Output = Fq2;
+
+    fn mul(self, rhs: Fq2) -> Fq2 {
+        // Karatsuba multiplication.
+        let aa = self.c0 * rhs.c0;
+        let bb = self.c1 * rhs.c1;
+        let c0 = aa - bb;
+        let c1 = (self.c0 + self.c1) * (rhs.c0 + rhs.c1) - aa - bb;
+        Fq2 { c0, c1 }
+    }
+}
+
+impl Field for Fq2 {
+    fn random(mut rng: impl RngCore) -> Self {
+        Fq2 {
+            c0: Fq::random(&mut rng),
+            c1: Fq::random(&mut rng),
+        }
+    }
+
+    fn zero() -> Self {
+        Fq2 {
+            c0: Fq::zero(),
+            c1: Fq::zero(),
+        }
+    }
+
+    fn one() -> Self {
+        Fq2 {
+            c0: Fq::one(),
+            c1: Fq::zero(),
+        }
+    }
+
+    fn is_zero(&self) -> Choice {
+        self.c0.is_zero() & self.c1.is_zero()
+    }
+
+    #[must_use]
+    fn square(&self) -> Self {
+        // (c0 + c1*u)^2 = (c0^2 - c1^2) + 2*c0*c1*u
+        let ab = self.c0 * self.c1;
+        let c0 = (self.c0 + self.c1) * (self.c0 - self.c1);
+        let c1 = ab + ab;
+        Fq2 { c0, c1 }
+    }
+
+    #[must_use]
+    fn double(&self) -> Self {
+        Fq2 {
+            c0: self.c0.double(),
+            c1: self.c1.double(),
+        }
+    }
+
+    fn invert(&self) -> CtOption<Self> {
+        self.norm().invert().map(|t| Fq2 {
+            c0: self.c0 * t,
+            c1: -(self.c1 * t),
+        })
+    }
+
+    fn sqrt(&self) -> CtOption<Self> {
+        // The "complex method": since this extension's non-residue is `-1`, a square root
+        // `x = x0 + x1*u` of `self = c0 + c1*u` satisfies `x0^2 - x1^2 = c0` and
+        // `norm(x) = x0^2 + x1^2`, and `norm(x)^2 = norm(x^2) = norm(self)`. So
+        // `gamma = sqrt(norm(self))` gives `norm(x) = +-gamma`, hence
+        // `x0^2 = (c0 +- gamma)/2` for whichever sign is itself a square in `Fq` (exactly
+        // one of the two is, unless `self` has no square root at all), and then
+        // `x1 = c1/(2*x0)`.
+        let two_inv = Fq::from(2u64).invert().expect("2 is invertible in Fq");
+
+        let gamma = match Option::<Fq>::from(self.norm().sqrt()) {
+            Some(gamma) => gamma,
+            None => return CtOption::new(Self::zero(), Choice::from(0)),
+        };
+
+        let delta_plus = (self.c0 + gamma) * two_inv;
+        let delta_minus = (self.c0 - gamma) * two_inv;
+
+        let x0 = match Option::<Fq>::from(delta_plus.sqrt()) {
+            Some(x0) => x0,
+            None => match Option::<Fq>::from(delta_minus.sqrt()) {
+                Some(x0) => x0,
+                None => return CtOption::new(Self::zero(), Choice::from(0)),
+            },
+        };
+
+        let candidate = if bool::from(x0.is_zero()) {
+            // `x0 = 0` means `x` is purely imaginary: `x1^2 = -c0`, with `c1` necessarily
+            // zero for `self` to be a square at all (caught by the final check below if not).
+            match Option::<Fq>::from((-self.c0).sqrt()) {
+                Some(x1) => Fq2 { c0: Fq::zero(), c1: x1 },
+                None => return CtOption::new(Self::zero(), Choice::from(0)),
+            }
+        } else {
+            let x1 = self.c1 * x0.double().invert().expect("x0 is nonzero");
+            Fq2 { c0: x0, c1: x1 }
+        };
+
+        // `gamma`/`delta_plus`/`delta_minus` only narrow down a *candidate*; verify it
+        // actually squares back to `self` rather than trusting the derivation blindly.
+        CtOption::new(candidate, candidate.square().ct_eq(self))
+    }
+}
@@ -0,0 +1,247 @@
+use core::ops::{Add, Mul, Neg, Sub};
+
+use ff::{Field, PrimeField};
+use rand_core::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+use super::fq::Fq;
+use super::fq2::Fq2;
+
+/// An element of `GF(p^6) = GF(p^2)[v] / (v^3 - \xi)`, where `\xi = (9, 1) \in Fq2` is the
+/// non-residue used to build the sextic twist of `G2` and, together with `Fq12`, the
+/// pairing target group.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Fq6 {
+    pub c0: Fq2,
+    pub c1: Fq2,
+    pub c2: Fq2,
+}
+
+impl ConditionallySelectable for Fq6 {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Fq6 {
+            c0: Fq2::conditional_select(&a.c0, &b.c0, choice),
+            c1: Fq2::conditional_select(&a.c1, &b.c1, choice),
+            c2: Fq2::conditional_select(&a.c2, &b.c2, choice),
+        }
+    }
+}
+
+impl ConstantTimeEq for Fq6 {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.c0.ct_eq(&other.c0) & self.c1.ct_eq(&other.c1) & self.c2.ct_eq(&other.c2)
+    }
+}
+
+impl Eq for Fq6 {}
+impl PartialEq for Fq6 {
+    fn eq(&self, other: &Self) -> bool {
+        bool::from(self.ct_eq(other))
+    }
+}
+
+impl Fq6 {
+    /// Multiplies this element by the non-residue used to build `Fq12`, i.e. by `v`.
+    pub fn mul_by_nonresidue(&self) -> Self {
+        Fq6 {
+            c0: self.c2.mul_by_nonresidue(),
+            c1: self.c0,
+            c2: self.c1,
+        }
+    }
+
+    /// Scales this element by an `Fq2` scalar, i.e. multiplies each coefficient by `s`.
+    pub(crate) fn scale_by_fq2(&self, s: Fq2) -> Self {
+        Fq6 {
+            c0: self.c0 * s,
+            c1: self.c1 * s,
+            c2: self.c2 * s,
+        }
+    }
+
+    /// Applies the degree-2 Frobenius endomorphism `x -> x^(p^2)` to this element. Used by
+    /// [`Fq12::frobenius_map2`](super::fq12::Fq12::frobenius_map2), in turn used by the
+    /// BN254 final exponentiation's "easy part" (see `bn256::MillerLoopResult`).
+    ///
+    /// Every coefficient of `self` already lies in `Fq2`, on which `x -> x^(p^2)` is the
+    /// identity (`Fq2` has exactly `p^2` elements, so the map's order divides 2), so only
+    /// the powers of `v` (`Fq6`'s own generator over `Fq2`, with `v^3 = \xi`) need
+    /// correcting: `v^(p^2) = \gamma_1 \cdot v` and `v^(2p^2) = \gamma_2 \cdot v^2`, for
+    /// `\gamma_1 = \xi^{(p^2-1)/3}` and `\gamma_2 = \xi^{2(p^2-1)/3}`.
+    pub(crate) fn frobenius_map2(&self) -> Self {
+        Fq6 {
+            c0: self.c0,
+            c1: self.c1 * frobenius_gamma1_2(),
+            c2: self.c2 * frobenius_gamma2_2(),
+        }
+    }
+}
+
+/// `\gamma_1 = \xi^{(p^2-1)/3} \in Fq2`, used by [`Fq6::frobenius_map2`]. Computed from the
+/// curve's own documented `p` (see `bn256::fq`) and `\xi = (9, 1)` (see
+/// [`Fq2::mul_by_nonresidue`]), not an independently-sourced literal.
+pub(crate) fn frobenius_gamma1_2() -> Fq2 {
+    Fq2 {
+        c0: fq_from_canonical_le([
+            0x48, 0xfd, 0x7c, 0x60, 0xe5, 0x44, 0xbd, 0xe4, 0x3d, 0x6e, 0x96, 0xbb, 0x9f, 0x06,
+            0x8f, 0xc2, 0xb0, 0xcc, 0xac, 0xe0, 0xe7, 0xd9, 0x6d, 0x5e, 0x29, 0xa0, 0x31, 0xe1,
+            0x72, 0x4e, 0x64, 0x30,
+        ]),
+        c1: Fq::zero(),
+    }
+}
+
+/// `\gamma_2 = \xi^{2(p^2-1)/3} \in Fq2`, used by [`Fq6::frobenius_map2`].
+pub(crate) fn frobenius_gamma2_2() -> Fq2 {
+    Fq2 {
+        c0: fq_from_canonical_le([
+            0xfe, 0xff, 0xff, 0x77, 0x31, 0x47, 0x63, 0x57, 0x4f, 0x5c, 0xdb, 0xac, 0xf1, 0x63,
+            0xf2, 0xd4, 0xac, 0x8b, 0xd4, 0xa0, 0xce, 0x6b, 0xe2, 0x59, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+        ]),
+        c1: Fq::zero(),
+    }
+}
+
+/// `\gamma_3 = \xi^{(p^2-1)/6} \in Fq2`, the coefficient `w^(p^2)` picks up in `Fq12`'s own
+/// degree-2 Frobenius (see
+/// [`Fq12::frobenius_map2`](super::fq12::Fq12::frobenius_map2)).
+pub(crate) fn frobenius_gamma3_2() -> Fq2 {
+    Fq2 {
+        c0: fq_from_canonical_le([
+            0x49, 0xfd, 0x7c, 0x60, 0xe5, 0x44, 0xbd, 0xe4, 0x3d, 0x6e, 0x96, 0xbb, 0x9f, 0x06,
+            0x8f, 0xc2, 0xb0, 0xcc, 0xac, 0xe0, 0xe7, 0xd9, 0x6d, 0x5e, 0x29, 0xa0, 0x31, 0xe1,
+            0x72, 0x4e, 0x64, 0x30,
+        ]),
+        c1: Fq::zero(),
+    }
+}
+
+fn fq_from_canonical_le(bytes: [u8; 32]) -> Fq {
+    let mut repr = <Fq as PrimeField>::Repr::default();
+    repr.as_mut().copy_from_slice(&bytes);
+    Fq::from_repr(repr).unwrap()
+}
+
+impl Add for Fq6 {
+    type Output = Fq6;
+
+    fn add(self, rhs: Fq6) -> Fq6 {
+        Fq6 {
+            c0: self.c0 + rhs.c0,
+            c1: self.c1 + rhs.c1,
+            c2: self.c2 + rhs.c2,
+        }
+    }
+}
+
+impl Sub for Fq6 {
+    type Output = Fq6;
+
+    fn sub(self, rhs: Fq6) -> Fq6 {
+        Fq6 {
+            c0: self.c0 - rhs.c0,
+            c1: self.c1 - rhs.c1,
+            c2: self.c2 - rhs.c2,
+        }
+    }
+}
+
+impl Neg for Fq6 {
+    type Output = Fq6;
+
+    fn neg(self) -> Fq6 {
+        Fq6 {
+            c0: -self.c0,
+            c1: -self.c1,
+            c2: -self.c2,
+        }
+    }
+}
+
+impl Mul for Fq6 {
+    type Output = Fq6;
+
+    fn mul(self, rhs: Fq6) -> Fq6 {
+        // Devegili et al., "Multiplication and Squaring on Pairing-Friendly Fields".
+        let a_a = self.c0 * rhs.c0;
+        let b_b = self.c1 * rhs.c1;
+        let c_c = self.c2 * rhs.c2;
+
+        let t1 = (self.c1 + self.c2) * (rhs.c1 + rhs.c2) - b_b - c_c;
+        let t1 = t1.mul_by_nonresidue() + a_a;
+
+        let t2 = (self.c0 + self.c1) * (rhs.c0 + rhs.c1) - a_a - b_b;
+        let t2 = t2 + c_c.mul_by_nonresidue();
+
+        let t3 = (self.c0 + self.c2) * (rhs.c0 + rhs.c2) - a_a - c_c + b_b;
+
+        Fq6 {
+            c0: t1,
+            c1: t2,
+            c2: t3,
+        }
+    }
+}
+
+impl Field for Fq6 {
+    fn random(mut rng: impl RngCore) -> Self {
+        Fq6 {
+            c0: Fq2::random(&mut rng),
+            c1: Fq2::random(&mut rng),
+            c2: Fq2::random(&mut rng),
+        }
+    }
+
+    fn zero() -> Self {
+        Fq6 {
+            c0: Fq2::zero(),
+            c1: Fq2::zero(),
+            c2: Fq2::zero(),
+        }
+    }
+
+    fn one() -> Self {
+        Fq6 {
+            c0: Fq2::one(),
+            c1: Fq2::zero(),
+            c2: Fq2::zero(),
+        }
+    }
+
+    fn is_zero(&self) -> Choice {
+        self.c0.is_zero() & self.c1.is_zero() & self.c2.is_zero()
+    }
+
+    #[must_use]
+    fn square(&self) -> Self {
+        (*self) * (*self)
+    }
+
+    #[must_use]
+    fn double(&self) -> Self {
+        Fq6 {
+            c0: self.c0.double(),
+            c1: self.c1.double(),
+            c2: self.c2.double(),
+        }
+    }
+
+    fn invert(&self) -> CtOption<Self> {
+        let c0 = self.c2.mul_by_nonresidue() * self.c1.neg() + self.c0.square();
+        let c1 = self.c2.square().mul_by_nonresidue() - self.c0 * self.c1;
+        let c2 = self.c1.square() - self.c0 * self.c2;
+
+        let tmp = ((self.c2 * c1) + (self.c1 * c2)).mul_by_nonresidue() + (self.c0 * c0);
+
+        tmp.invert().map(|t| Fq6 {
+            c0: t * c0,
+            c1: t * c1,
+            c2: t * c2,
+        })
+    }
+
+    fn sqrt(&self) -> CtOption<Self> {
+        CtOption::new(Self::zero(), Choice::from(0))
+    }
+}
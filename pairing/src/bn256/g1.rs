@@ -0,0 +1,650 @@
+use core::fmt;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use core::convert::TryInto;
+
+use ff::{Field, PrimeField};
+use group::{Compress, GroupDecodingError, Validate};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+use super::fq::{Fq, Fr};
+use crate::PairingCurveAffine;
+
+/// `y^2 = x^3 + 3`, the short Weierstrass curve hosting `G1`.
+const B: Fq = Fq::from_raw([3, 0, 0, 0]);
+
+/// A projective (Jacobian) point on the BN254 `G1` curve.
+#[derive(Copy, Clone, Debug)]
+pub struct G1 {
+    pub(crate) x: Fq,
+    pub(crate) y: Fq,
+    pub(crate) z: Fq,
+}
+
+/// An affine point on the BN254 `G1` curve.
+#[derive(Copy, Clone, Debug)]
+pub struct G1Affine {
+    pub(crate) x: Fq,
+    pub(crate) y: Fq,
+    pub(crate) infinity: Choice,
+}
+
+impl fmt::Display for G1Affine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if bool::from(self.infinity) {
+            write!(f, "G1Affine(infinity)")
+        } else {
+            write!(f, "G1Affine(x={:?}, y={:?})", self.x, self.y)
+        }
+    }
+}
+
+impl ConstantTimeEq for G1Affine {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        let both_infinity = self.infinity & other.infinity;
+        let neither_infinity = !self.infinity & !other.infinity;
+        both_infinity | (neither_infinity & self.x.ct_eq(&other.x) & self.y.ct_eq(&other.y))
+    }
+}
+
+impl PartialEq for G1Affine {
+    fn eq(&self, other: &Self) -> bool {
+        bool::from(self.ct_eq(other))
+    }
+}
+impl Eq for G1Affine {}
+
+impl ConditionallySelectable for G1Affine {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        G1Affine {
+            x: Fq::conditional_select(&a.x, &b.x, choice),
+            y: Fq::conditional_select(&a.y, &b.y, choice),
+            infinity: Choice::conditional_select(&a.infinity, &b.infinity, choice),
+        }
+    }
+}
+
+impl Neg for G1Affine {
+    type Output = G1Affine;
+
+    fn neg(self) -> G1Affine {
+        G1Affine {
+            x: self.x,
+            y: Fq::conditional_select(&-self.y, &Fq::one(), self.infinity),
+            infinity: self.infinity,
+        }
+    }
+}
+
+impl G1Affine {
+    /// Returns the identity element (point at infinity).
+    pub fn identity() -> Self {
+        G1Affine {
+            x: Fq::zero(),
+            y: Fq::one(),
+            infinity: Choice::from(1),
+        }
+    }
+
+    /// Returns the fixed generator of `G1`: `(1, 2)`.
+    pub fn generator() -> Self {
+        G1Affine {
+            x: Fq::one(),
+            y: Fq::from_raw([2, 0, 0, 0]),
+            infinity: Choice::from(0),
+        }
+    }
+
+    /// Converts this point to its Jacobian representation.
+    pub fn to_curve(&self) -> G1 {
+        G1 {
+            x: self.x,
+            y: self.y,
+            z: Fq::conditional_select(&Fq::one(), &Fq::zero(), self.infinity),
+        }
+    }
+
+    /// Determines whether this point represents the point at infinity.
+    pub fn is_identity(&self) -> Choice {
+        self.infinity
+    }
+
+    /// Determines whether `(x, y)` satisfies the curve equation `y^2 = x^3 + 3`.
+    pub fn is_on_curve(&self) -> Choice {
+        (self.y.square() - (self.x.square() * self.x + B)).is_zero() | self.infinity
+    }
+
+    /// Serializes this point as 64 bytes: `x` then `y`, each big-endian, with the point at
+    /// infinity encoded as all-zero coordinates. `Fq`'s modulus has top byte `0x30`, so only
+    /// the top two bits of the first byte are unused by its values and are repurposed as
+    /// flags: bit 7 is the compression flag (always clear here, since this is the
+    /// uncompressed encoding) and bit 6 is set exactly when the point is the identity. Bit 5
+    /// is part of `x`.
+    pub fn to_uncompressed(&self) -> [u8; 64] {
+        let mut out = [0u8; 64];
+
+        let mut x = self.x.to_repr();
+        x.as_mut().reverse();
+        let mut y = self.y.to_repr();
+        y.as_mut().reverse();
+        out[..32].copy_from_slice(x.as_ref());
+        out[32..].copy_from_slice(y.as_ref());
+
+        if bool::from(self.infinity) {
+            out = [0u8; 64];
+            out[0] |= 0b0100_0000;
+        }
+
+        out
+    }
+
+    /// Deserializes a point from the 64-byte encoding produced by
+    /// [`to_uncompressed`](G1Affine::to_uncompressed), distinguishing *why* a malformed or
+    /// invalid encoding was rejected rather than collapsing every failure into `None`.
+    ///
+    /// `G1`'s cofactor is 1, so unlike [`G2Affine::from_uncompressed_checked`] every point
+    /// on the curve is already in the correct subgroup; no subgroup check is made and
+    /// [`GroupDecodingError::NotInSubgroup`] is never returned.
+    pub fn from_uncompressed_checked(bytes: &[u8; 64]) -> Result<G1Affine, GroupDecodingError> {
+        if bytes[0] & 0b1000_0000 != 0 {
+            return Err(GroupDecodingError::UnexpectedCompressionMode);
+        }
+
+        let infinity_flag_set = bytes[0] & 0b0100_0000 != 0;
+        let sign_flag_set = bytes[0] & 0b0010_0000 != 0;
+        if infinity_flag_set && sign_flag_set {
+            return Err(GroupDecodingError::UnexpectedInformation);
+        }
+
+        let mut x = [0u8; 32];
+        x.copy_from_slice(&bytes[..32]);
+        x[0] &= 0b0011_1111;
+        let mut y = [0u8; 32];
+        y.copy_from_slice(&bytes[32..64]);
+
+        if infinity_flag_set {
+            if x.iter().any(|&b| b != 0) || y.iter().any(|&b| b != 0) {
+                return Err(GroupDecodingError::NonCanonicalIdentity);
+            }
+            return Ok(G1Affine::identity());
+        }
+
+        x.reverse();
+        y.reverse();
+
+        let mut x_repr = <Fq as PrimeField>::Repr::default();
+        x_repr.as_mut().copy_from_slice(&x);
+        let mut y_repr = <Fq as PrimeField>::Repr::default();
+        y_repr.as_mut().copy_from_slice(&y);
+
+        let x = Fq::from_repr(x_repr);
+        let y = Fq::from_repr(y_repr);
+        if bool::from(x.is_none()) || bool::from(y.is_none()) {
+            return Err(GroupDecodingError::CoordinateNotCanonical);
+        }
+
+        let point = G1Affine {
+            x: x.unwrap(),
+            y: y.unwrap(),
+            infinity: Choice::from(0),
+        };
+        if !bool::from(point.is_on_curve()) {
+            return Err(GroupDecodingError::NotOnCurve);
+        }
+
+        Ok(point)
+    }
+
+    /// The byte length of this curve's encoding under `compress`: 32 bytes (`x` plus a sign
+    /// bit for `y`) when compressed, or [`to_uncompressed`](G1Affine::to_uncompressed)'s 64
+    /// bytes (`x` then `y`) when not -- so callers can size buffers from the mode rather
+    /// than hard-coding the offset.
+    pub fn serialized_size(compress: Compress) -> usize {
+        match compress {
+            Compress::Yes => 32,
+            Compress::No => 64,
+        }
+    }
+
+    /// Serializes this point under `compress`, returning exactly
+    /// [`serialized_size(compress)`](G1Affine::serialized_size) bytes.
+    pub fn serialize_with_mode(&self, compress: Compress) -> Vec<u8> {
+        match compress {
+            Compress::No => self.to_uncompressed().to_vec(),
+            Compress::Yes => {
+                let mut x = self.x.to_repr();
+                x.as_mut().reverse();
+                let mut out = x.as_ref().to_vec();
+                out[0] &= 0b0001_1111;
+
+                if bool::from(self.infinity) {
+                    out.iter_mut().for_each(|b| *b = 0);
+                    out[0] |= 0b0100_0000;
+                } else {
+                    out[0] |= 0b1000_0000;
+                    if self.y.to_repr().as_ref()[0] & 1 == 1 {
+                        out[0] |= 0b0010_0000;
+                    }
+                }
+
+                out
+            }
+        }
+    }
+
+    /// Deserializes a point encoded by [`serialize_with_mode`](G1Affine::serialize_with_mode)
+    /// under the matching `compress` mode, distinguishing *why* a malformed or invalid
+    /// encoding was rejected, and (for `Compress::Yes`) recovering `y` from its sign bit via
+    /// `y = sqrt(x^3 + 3)`.
+    ///
+    /// `validate` gates only the subgroup check; as on [`from_uncompressed_checked`]
+    /// (`G1`'s cofactor is 1, so that check is a no-op either way here), coordinate-range and
+    /// on-curve checks always run.
+    ///
+    /// [`from_uncompressed_checked`]: G1Affine::from_uncompressed_checked
+    pub fn deserialize_with_mode(
+        bytes: &[u8],
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<G1Affine, GroupDecodingError> {
+        let _ = validate; // no-op for a cofactor-1 curve; accepted for API symmetry with G2.
+
+        match compress {
+            Compress::No => {
+                let bytes: &[u8; 64] = bytes
+                    .try_into()
+                    .map_err(|_| GroupDecodingError::UnexpectedCompressionMode)?;
+                G1Affine::from_uncompressed_checked(bytes)
+            }
+            Compress::Yes => {
+                let bytes: &[u8; 32] = bytes
+                    .try_into()
+                    .map_err(|_| GroupDecodingError::UnexpectedCompressionMode)?;
+
+                if bytes[0] & 0b1000_0000 == 0 {
+                    return Err(GroupDecodingError::UnexpectedCompressionMode);
+                }
+
+                let infinity_flag_set = bytes[0] & 0b0100_0000 != 0;
+                let sign_flag_set = bytes[0] & 0b0010_0000 != 0;
+                if infinity_flag_set && sign_flag_set {
+                    return Err(GroupDecodingError::UnexpectedInformation);
+                }
+
+                let mut x = *bytes;
+                x[0] &= 0b0001_1111;
+
+                if infinity_flag_set {
+                    if x.iter().any(|&b| b != 0) {
+                        return Err(GroupDecodingError::NonCanonicalIdentity);
+                    }
+                    return Ok(G1Affine::identity());
+                }
+
+                x.reverse();
+                let mut x_repr = <Fq as PrimeField>::Repr::default();
+                x_repr.as_mut().copy_from_slice(&x);
+                let x = Fq::from_repr(x_repr);
+                if bool::from(x.is_none()) {
+                    return Err(GroupDecodingError::CoordinateNotCanonical);
+                }
+                let x = x.unwrap();
+
+                let x3b = x.square() * x + B;
+                let y = x3b.sqrt();
+                if bool::from(y.is_none()) {
+                    return Err(GroupDecodingError::NotOnCurve);
+                }
+                let mut y = y.unwrap();
+                let y_is_odd = y.to_repr().as_ref()[0] & 1 == 1;
+                if y_is_odd != sign_flag_set {
+                    y = -y;
+                }
+
+                Ok(G1Affine {
+                    x,
+                    y,
+                    infinity: Choice::from(0),
+                })
+            }
+        }
+    }
+}
+
+impl Mul<Fr> for G1Affine {
+    type Output = G1;
+
+    fn mul(self, rhs: Fr) -> G1 {
+        self.to_curve() * rhs
+    }
+}
+
+impl<'a> Mul<&'a Fr> for G1Affine {
+    type Output = G1;
+
+    fn mul(self, rhs: &'a Fr) -> G1 {
+        self.to_curve() * *rhs
+    }
+}
+
+impl From<G1> for G1Affine {
+    fn from(p: G1) -> G1Affine {
+        p.to_affine()
+    }
+}
+
+impl G1 {
+    /// Returns the identity element (point at infinity).
+    pub fn identity() -> Self {
+        G1 {
+            x: Fq::zero(),
+            y: Fq::one(),
+            z: Fq::zero(),
+        }
+    }
+
+    /// Returns the fixed generator of `G1`.
+    pub fn generator() -> Self {
+        G1Affine::generator().to_curve()
+    }
+
+    /// Converts this point into its affine representation.
+    pub fn to_affine(&self) -> G1Affine {
+        let zinv = self.z.invert().unwrap_or_else(Fq::zero);
+        let zinv2 = zinv.square();
+        let x = self.x * zinv2;
+        let y = self.y * zinv2 * zinv;
+
+        let is_identity = self.z.is_zero();
+        G1Affine {
+            x: Fq::conditional_select(&x, &Fq::zero(), is_identity),
+            y: Fq::conditional_select(&y, &Fq::one(), is_identity),
+            infinity: is_identity,
+        }
+    }
+
+    /// Doubles this point.
+    #[must_use]
+    pub fn double(&self) -> G1 {
+        // Standard Jacobian doubling (dbl-2009-l).
+        let a = self.x.square();
+        let b = self.y.square();
+        let c = b.square();
+        let d = ((self.x + b).square() - a - c).double();
+        let e = a.double() + a;
+        let f = e.square();
+        let x3 = f - d.double();
+        let y3 = e * (d - x3) - c.double().double().double();
+        let z3 = (self.y * self.z).double();
+
+        G1 {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// Determines whether this point represents the point at infinity.
+    pub fn is_identity(&self) -> Choice {
+        self.z.is_zero()
+    }
+}
+
+impl Default for G1 {
+    fn default() -> Self {
+        G1::identity()
+    }
+}
+
+impl Add for G1 {
+    type Output = G1;
+
+    fn add(self, rhs: G1) -> G1 {
+        self.add(&rhs)
+    }
+}
+
+impl<'a> Add<&'a G1> for G1 {
+    type Output = G1;
+
+    fn add(self, rhs: &'a G1) -> G1 {
+        // Complete Jacobian addition (add-2007-bl), falling back to doubling/identity
+        // handling as needed.
+        if bool::from(self.is_identity()) {
+            return *rhs;
+        }
+        if bool::from(rhs.is_identity()) {
+            return self;
+        }
+
+        let z1z1 = self.z.square();
+        let z2z2 = rhs.z.square();
+        let u1 = self.x * z2z2;
+        let u2 = rhs.x * z1z1;
+        let s1 = self.y * rhs.z * z2z2;
+        let s2 = rhs.y * self.z * z1z1;
+
+        if u1 == u2 {
+            return if s1 == s2 { self.double() } else { G1::identity() };
+        }
+
+        let h = u2 - u1;
+        let i = h.double().square();
+        let j = h * i;
+        let r = (s2 - s1).double();
+        let v = u1 * i;
+        let x3 = r.square() - j - v.double();
+        let y3 = r * (v - x3) - (s1 * j).double();
+        let z3 = ((self.z + rhs.z).square() - z1z1 - z2z2) * h;
+
+        G1 {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+}
+
+impl AddAssign for G1 {
+    fn add_assign(&mut self, rhs: G1) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for G1 {
+    type Output = G1;
+
+    fn sub(self, rhs: G1) -> G1 {
+        self + (-rhs)
+    }
+}
+
+impl SubAssign for G1 {
+    fn sub_assign(&mut self, rhs: G1) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for G1 {
+    type Output = G1;
+
+    fn neg(self) -> G1 {
+        G1 {
+            x: self.x,
+            y: -self.y,
+            z: self.z,
+        }
+    }
+}
+
+impl Mul<Fr> for G1 {
+    type Output = G1;
+
+    fn mul(self, scalar: Fr) -> G1 {
+        // Simple double-and-add; `Wnaf` provides a faster path for repeated scalar
+        // multiplication by a fixed base or scalar.
+        let mut acc = G1::identity();
+        for bit in scalar
+            .to_repr()
+            .as_ref()
+            .iter()
+            .rev()
+            .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
+        {
+            acc = acc.double();
+            if bit {
+                acc += self;
+            }
+        }
+        acc
+    }
+}
+
+impl MulAssign<Fr> for G1 {
+    fn mul_assign(&mut self, rhs: Fr) {
+        *self = *self * rhs;
+    }
+}
+
+impl From<G1Affine> for G1 {
+    fn from(p: G1Affine) -> G1 {
+        p.to_curve()
+    }
+}
+
+impl PairingCurveAffine for G1Affine {
+    type Prepared = G1Affine;
+    type Pair = super::g2::G2Affine;
+    type PairingResult = super::Gt;
+
+    fn prepare(&self) -> Self::Prepared {
+        *self
+    }
+
+    fn pairing_with(&self, other: &Self::Pair) -> Self::PairingResult {
+        super::Bn256::pairing(*self, *other)
+    }
+}
+
+impl crate::CurveParameters for G1Affine {
+    fn coeff_b() -> Fq {
+        B
+    }
+
+    fn from_xy(x: Fq, y: Fq) -> Option<Self> {
+        let point = G1Affine {
+            x,
+            y,
+            infinity: Choice::from(0),
+        };
+        if bool::from(point.is_on_curve()) {
+            Some(point)
+        } else {
+            None
+        }
+    }
+}
+
+impl crate::SmallCofactorCurveParameters for G1Affine {
+    // `G1`'s cofactor is 1: it is already its own prime-order subgroup.
+    const COFACTOR: u64 = 1;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // BN254's `Fq` modulus, big-endian -- the decimal value documented on `Fq` itself in
+    // `bn256::fq`, not a value reconstructed from any internal representation. Every byte
+    // string `>= MODULUS` is a non-canonical `Fq` encoding.
+    const MODULUS: [u8; 32] = [
+        0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58,
+        0x5d, 0x97, 0x81, 0x6a, 0x91, 0x68, 0x71, 0xca, 0x8d, 0x3c, 0x20, 0x8c, 0x16, 0xd8, 0x7c,
+        0xfd, 0x47,
+    ];
+
+    #[test]
+    fn uncompressed_round_trip() {
+        let p = G1Affine::generator();
+        let bytes = p.to_uncompressed();
+        assert_eq!(G1Affine::from_uncompressed_checked(&bytes).unwrap(), p);
+
+        let identity = G1Affine::identity();
+        let bytes = identity.to_uncompressed();
+        assert_eq!(G1Affine::from_uncompressed_checked(&bytes).unwrap(), identity);
+    }
+
+    #[test]
+    fn uncompressed_rejects_compression_flag() {
+        let mut bytes = G1Affine::generator().to_uncompressed();
+        bytes[0] |= 0b1000_0000;
+        assert_eq!(
+            G1Affine::from_uncompressed_checked(&bytes),
+            Err(GroupDecodingError::UnexpectedCompressionMode)
+        );
+    }
+
+    #[test]
+    fn uncompressed_rejects_infinity_with_sign_bit() {
+        let mut bytes = [0u8; 64];
+        bytes[0] = 0b0100_0000 | 0b0010_0000;
+        assert_eq!(
+            G1Affine::from_uncompressed_checked(&bytes),
+            Err(GroupDecodingError::UnexpectedInformation)
+        );
+    }
+
+    #[test]
+    fn uncompressed_rejects_nonzero_coordinates_on_infinity() {
+        let mut bytes = [0u8; 64];
+        bytes[0] = 0b0100_0000;
+        bytes[63] = 1;
+        assert_eq!(
+            G1Affine::from_uncompressed_checked(&bytes),
+            Err(GroupDecodingError::NonCanonicalIdentity)
+        );
+    }
+
+    #[test]
+    fn uncompressed_rejects_noncanonical_coordinate() {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&MODULUS);
+        bytes[63] = 1;
+        assert_eq!(
+            G1Affine::from_uncompressed_checked(&bytes),
+            Err(GroupDecodingError::CoordinateNotCanonical)
+        );
+    }
+
+    #[test]
+    fn uncompressed_rejects_off_curve_point() {
+        let mut bytes = [0u8; 64];
+        bytes[31] = 1; // x = 1
+        bytes[63] = 1; // y = 1, but 1^2 != 1^3 + 3
+        assert_eq!(
+            G1Affine::from_uncompressed_checked(&bytes),
+            Err(GroupDecodingError::NotOnCurve)
+        );
+    }
+
+    #[test]
+    fn compressed_round_trip() {
+        for p in [G1Affine::generator(), G1Affine::identity()] {
+            let bytes = p.serialize_with_mode(Compress::Yes);
+            assert_eq!(
+                G1Affine::deserialize_with_mode(&bytes, Compress::Yes, Validate::Yes).unwrap(),
+                p
+            );
+        }
+    }
+
+    #[test]
+    fn compressed_rejects_missing_compression_flag() {
+        let mut bytes = G1Affine::generator().serialize_with_mode(Compress::Yes);
+        bytes[0] &= 0b0111_1111;
+        assert_eq!(
+            G1Affine::deserialize_with_mode(&bytes, Compress::Yes, Validate::Yes),
+            Err(GroupDecodingError::UnexpectedCompressionMode)
+        );
+    }
+}
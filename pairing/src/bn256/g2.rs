@@ -0,0 +1,865 @@
+use core::fmt;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use core::convert::TryInto;
+
+use ff::{Field, PrimeField};
+use group::{Compress, GroupDecodingError, Validate};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+use super::fq::{Fq, Fr};
+use super::fq2::Fq2;
+use super::SIX_U_PLUS_2_NAF;
+use crate::PairingCurveAffine;
+
+/// `y^2 = x^3 + 3/(9+u)`, the sextic twist of the `G1` curve equation, hosting `G2`.
+fn twist_b() -> Fq2 {
+    Fq2 {
+        c0: super::fq::Fq::from_raw([
+            3632125457679333605,
+            13093307605518643107,
+            9348936922344483523,
+            3104278944836790958,
+        ]),
+        c1: super::fq::Fq::from_raw([
+            16474938222586303954,
+            12056031220135172178,
+            14784384838321896948,
+            42524369107353300,
+        ]),
+    }
+}
+
+/// A projective (Jacobian) point on `G2`.
+#[derive(Copy, Clone, Debug)]
+pub struct G2 {
+    pub(crate) x: Fq2,
+    pub(crate) y: Fq2,
+    pub(crate) z: Fq2,
+}
+
+/// An affine point on `G2`.
+#[derive(Copy, Clone, Debug)]
+pub struct G2Affine {
+    pub(crate) x: Fq2,
+    pub(crate) y: Fq2,
+    pub(crate) infinity: Choice,
+}
+
+impl fmt::Display for G2Affine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if bool::from(self.infinity) {
+            write!(f, "G2Affine(infinity)")
+        } else {
+            write!(f, "G2Affine(x={:?}, y={:?})", self.x, self.y)
+        }
+    }
+}
+
+impl ConstantTimeEq for G2Affine {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        let both_infinity = self.infinity & other.infinity;
+        let neither_infinity = !self.infinity & !other.infinity;
+        both_infinity | (neither_infinity & self.x.ct_eq(&other.x) & self.y.ct_eq(&other.y))
+    }
+}
+
+impl PartialEq for G2Affine {
+    fn eq(&self, other: &Self) -> bool {
+        bool::from(self.ct_eq(other))
+    }
+}
+impl Eq for G2Affine {}
+
+impl ConditionallySelectable for G2Affine {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        G2Affine {
+            x: Fq2::conditional_select(&a.x, &b.x, choice),
+            y: Fq2::conditional_select(&a.y, &b.y, choice),
+            infinity: Choice::conditional_select(&a.infinity, &b.infinity, choice),
+        }
+    }
+}
+
+impl Neg for G2Affine {
+    type Output = G2Affine;
+
+    fn neg(self) -> G2Affine {
+        G2Affine {
+            x: self.x,
+            y: Fq2::conditional_select(&-self.y, &Fq2::one(), self.infinity),
+            infinity: self.infinity,
+        }
+    }
+}
+
+impl G2Affine {
+    /// Returns the identity element (point at infinity).
+    pub fn identity() -> Self {
+        G2Affine {
+            x: Fq2::zero(),
+            y: Fq2::one(),
+            infinity: Choice::from(1),
+        }
+    }
+
+    /// Converts this point to its Jacobian representation.
+    pub fn to_curve(&self) -> G2 {
+        G2 {
+            x: self.x,
+            y: self.y,
+            z: Fq2::conditional_select(&Fq2::one(), &Fq2::zero(), self.infinity),
+        }
+    }
+
+    /// Determines whether this point represents the point at infinity.
+    pub fn is_identity(&self) -> Choice {
+        self.infinity
+    }
+
+    /// Determines whether `(x, y)` satisfies `y^2 = x^3 + twist_b`.
+    pub fn is_on_curve(&self) -> Choice {
+        (self.y.square() - (self.x.square() * self.x + twist_b())).is_zero() | self.infinity
+    }
+
+    /// Determines whether this point lies in `G2`'s prime-order subgroup, by checking that
+    /// multiplying it by the (unreduced) group order `r` gives the identity. Unlike `G1`,
+    /// `G2`'s cofactor is not 1, so being on the curve doesn't already imply this.
+    fn is_in_correct_subgroup_assuming_on_curve(&self) -> Choice {
+        let mut acc = G2::identity();
+        for bit in Fr::char()
+            .as_ref()
+            .iter()
+            .rev()
+            .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
+        {
+            acc = acc.double();
+            if bit {
+                acc += self.to_curve();
+            }
+        }
+        acc.is_identity()
+    }
+
+    /// Serializes this point as 128 bytes: `x.c1`, `x.c0`, `y.c1`, `y.c0`, each 32 bytes
+    /// big-endian, with the point at infinity encoded as all-zero coordinates. `Fq`'s modulus
+    /// has top byte `0x30`, so only the top two bits of the first byte are unused by its
+    /// values and are repurposed as flags: bit 7 is the compression flag (always clear here,
+    /// since this is the uncompressed encoding) and bit 6 is set exactly when the point is
+    /// the identity. Bit 5 is part of `x.c1`.
+    pub fn to_uncompressed(&self) -> [u8; 128] {
+        let mut out = [0u8; 128];
+
+        let mut x_c1 = self.x.c1.to_repr();
+        x_c1.as_mut().reverse();
+        let mut x_c0 = self.x.c0.to_repr();
+        x_c0.as_mut().reverse();
+        let mut y_c1 = self.y.c1.to_repr();
+        y_c1.as_mut().reverse();
+        let mut y_c0 = self.y.c0.to_repr();
+        y_c0.as_mut().reverse();
+
+        out[..32].copy_from_slice(x_c1.as_ref());
+        out[32..64].copy_from_slice(x_c0.as_ref());
+        out[64..96].copy_from_slice(y_c1.as_ref());
+        out[96..].copy_from_slice(y_c0.as_ref());
+
+        if bool::from(self.infinity) {
+            out = [0u8; 128];
+            out[0] |= 0b0100_0000;
+        }
+
+        out
+    }
+
+    /// Deserializes a point from the 128-byte encoding produced by
+    /// [`to_uncompressed`](G2Affine::to_uncompressed), distinguishing *why* a malformed or
+    /// invalid encoding was rejected rather than collapsing every failure into `None`.
+    pub fn from_uncompressed_checked(bytes: &[u8; 128]) -> Result<G2Affine, GroupDecodingError> {
+        Self::decode_uncompressed(bytes, Validate::Yes)
+    }
+
+    fn decode_uncompressed(
+        bytes: &[u8; 128],
+        validate: Validate,
+    ) -> Result<G2Affine, GroupDecodingError> {
+        if bytes[0] & 0b1000_0000 != 0 {
+            return Err(GroupDecodingError::UnexpectedCompressionMode);
+        }
+
+        let infinity_flag_set = bytes[0] & 0b0100_0000 != 0;
+        let sign_flag_set = bytes[0] & 0b0010_0000 != 0;
+        if infinity_flag_set && sign_flag_set {
+            return Err(GroupDecodingError::UnexpectedInformation);
+        }
+
+        let mut x_c1 = [0u8; 32];
+        x_c1.copy_from_slice(&bytes[..32]);
+        x_c1[0] &= 0b0011_1111;
+        let mut x_c0 = [0u8; 32];
+        x_c0.copy_from_slice(&bytes[32..64]);
+        let mut y_c1 = [0u8; 32];
+        y_c1.copy_from_slice(&bytes[64..96]);
+        let mut y_c0 = [0u8; 32];
+        y_c0.copy_from_slice(&bytes[96..128]);
+
+        if infinity_flag_set {
+            let all_zero = [x_c1, x_c0, y_c1, y_c0]
+                .iter()
+                .all(|limb| limb.iter().all(|&b| b == 0));
+            if !all_zero {
+                return Err(GroupDecodingError::NonCanonicalIdentity);
+            }
+            return Ok(G2Affine::identity());
+        }
+
+        for limb in [&mut x_c1, &mut x_c0, &mut y_c1, &mut y_c0] {
+            limb.reverse();
+        }
+
+        let from_repr = |bytes: [u8; 32]| -> Option<Fq> {
+            let mut repr = <Fq as PrimeField>::Repr::default();
+            repr.as_mut().copy_from_slice(&bytes);
+            Option::from(Fq::from_repr(repr))
+        };
+
+        let (x_c1, x_c0, y_c1, y_c0) = match (
+            from_repr(x_c1),
+            from_repr(x_c0),
+            from_repr(y_c1),
+            from_repr(y_c0),
+        ) {
+            (Some(x_c1), Some(x_c0), Some(y_c1), Some(y_c0)) => (x_c1, x_c0, y_c1, y_c0),
+            _ => return Err(GroupDecodingError::CoordinateNotCanonical),
+        };
+
+        let point = G2Affine {
+            x: Fq2 { c0: x_c0, c1: x_c1 },
+            y: Fq2 { c0: y_c0, c1: y_c1 },
+            infinity: Choice::from(0),
+        };
+        if !bool::from(point.is_on_curve()) {
+            return Err(GroupDecodingError::NotOnCurve);
+        }
+        if let Validate::Yes = validate {
+            if !bool::from(point.is_in_correct_subgroup_assuming_on_curve()) {
+                return Err(GroupDecodingError::NotInSubgroup);
+            }
+        }
+
+        Ok(point)
+    }
+
+    /// The byte length of this curve's encoding under `compress`: 64 bytes (`x` plus a sign
+    /// bit for `y`) when compressed, or [`to_uncompressed`](G2Affine::to_uncompressed)'s 128
+    /// bytes when not.
+    pub fn serialized_size(compress: Compress) -> usize {
+        match compress {
+            Compress::Yes => 64,
+            Compress::No => 128,
+        }
+    }
+
+    /// Serializes this point under `compress`, returning exactly
+    /// [`serialized_size(compress)`](G2Affine::serialized_size) bytes. The compressed form
+    /// is `x.c1 || x.c0` (32 bytes each, the same layout as the first half of
+    /// [`to_uncompressed`](G2Affine::to_uncompressed)) plus the same flag bits `G1`'s
+    /// compressed encoding uses, with the sign bit taken from `y`'s non-zero component of
+    /// highest degree (`y.c1` if it's nonzero, else `y.c0`).
+    pub fn serialize_with_mode(&self, compress: Compress) -> Vec<u8> {
+        match compress {
+            Compress::No => self.to_uncompressed().to_vec(),
+            Compress::Yes => {
+                let mut x_c1 = self.x.c1.to_repr();
+                x_c1.as_mut().reverse();
+                let mut x_c0 = self.x.c0.to_repr();
+                x_c0.as_mut().reverse();
+                let mut out = vec![0u8; 64];
+                out[..32].copy_from_slice(x_c1.as_ref());
+                out[32..].copy_from_slice(x_c0.as_ref());
+                out[0] &= 0b0001_1111;
+
+                if bool::from(self.infinity) {
+                    out.iter_mut().for_each(|b| *b = 0);
+                    out[0] |= 0b0100_0000;
+                } else {
+                    out[0] |= 0b1000_0000;
+                    if y_sign(&self.y) {
+                        out[0] |= 0b0010_0000;
+                    }
+                }
+
+                out
+            }
+        }
+    }
+
+    /// Deserializes a point encoded by [`serialize_with_mode`](G2Affine::serialize_with_mode)
+    /// under the matching `compress` mode, distinguishing *why* a malformed or invalid
+    /// encoding was rejected, and (for `Compress::Yes`) recovering `y` from its sign bit via
+    /// `y = sqrt(x^3 + twist_b)`.
+    ///
+    /// `validate` gates only the subgroup check; coordinate-range and on-curve checks
+    /// always run.
+    pub fn deserialize_with_mode(
+        bytes: &[u8],
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<G2Affine, GroupDecodingError> {
+        match compress {
+            Compress::No => {
+                let bytes: &[u8; 128] = bytes
+                    .try_into()
+                    .map_err(|_| GroupDecodingError::UnexpectedCompressionMode)?;
+                Self::decode_uncompressed(bytes, validate)
+            }
+            Compress::Yes => {
+                let bytes: &[u8; 64] = bytes
+                    .try_into()
+                    .map_err(|_| GroupDecodingError::UnexpectedCompressionMode)?;
+
+                if bytes[0] & 0b1000_0000 == 0 {
+                    return Err(GroupDecodingError::UnexpectedCompressionMode);
+                }
+
+                let infinity_flag_set = bytes[0] & 0b0100_0000 != 0;
+                let sign_flag_set = bytes[0] & 0b0010_0000 != 0;
+                if infinity_flag_set && sign_flag_set {
+                    return Err(GroupDecodingError::UnexpectedInformation);
+                }
+
+                let mut x_c1 = *bytes;
+                x_c1[0] &= 0b0001_1111;
+                let x_c1 = &x_c1[..32];
+                let x_c0 = &bytes[32..64];
+
+                if infinity_flag_set {
+                    if x_c1.iter().chain(x_c0).any(|&b| b != 0) {
+                        return Err(GroupDecodingError::NonCanonicalIdentity);
+                    }
+                    return Ok(G2Affine::identity());
+                }
+
+                let from_repr = |bytes: &[u8]| -> Option<Fq> {
+                    let mut reversed = [0u8; 32];
+                    reversed.copy_from_slice(bytes);
+                    reversed.reverse();
+                    let mut repr = <Fq as PrimeField>::Repr::default();
+                    repr.as_mut().copy_from_slice(&reversed);
+                    Option::from(Fq::from_repr(repr))
+                };
+                let (x_c1, x_c0) = match (from_repr(x_c1), from_repr(x_c0)) {
+                    (Some(x_c1), Some(x_c0)) => (x_c1, x_c0),
+                    _ => return Err(GroupDecodingError::CoordinateNotCanonical),
+                };
+                let x = Fq2 { c0: x_c0, c1: x_c1 };
+
+                let x3b = x.square() * x + twist_b();
+                let mut y = match Option::<Fq2>::from(x3b.sqrt()) {
+                    Some(y) => y,
+                    None => return Err(GroupDecodingError::NotOnCurve),
+                };
+                if y_sign(&y) != sign_flag_set {
+                    y = -y;
+                }
+
+                let point = G2Affine {
+                    x,
+                    y,
+                    infinity: Choice::from(0),
+                };
+                if let Validate::Yes = validate {
+                    if !bool::from(point.is_in_correct_subgroup_assuming_on_curve()) {
+                        return Err(GroupDecodingError::NotInSubgroup);
+                    }
+                }
+
+                Ok(point)
+            }
+        }
+    }
+}
+
+/// The sign bit used by `G2`'s compressed encoding: `y`'s non-zero component of highest
+/// degree (`c1` if it's nonzero, else `c0`), matching how `G1`'s compressed encoding uses
+/// its single coordinate's parity.
+fn y_sign(y: &Fq2) -> bool {
+    if bool::from(y.c1.is_zero()) {
+        y.c0.to_repr().as_ref()[0] & 1 == 1
+    } else {
+        y.c1.to_repr().as_ref()[0] & 1 == 1
+    }
+}
+
+impl Mul<Fr> for G2Affine {
+    type Output = G2;
+
+    fn mul(self, rhs: Fr) -> G2 {
+        self.to_curve() * rhs
+    }
+}
+
+impl<'a> Mul<&'a Fr> for G2Affine {
+    type Output = G2;
+
+    fn mul(self, rhs: &'a Fr) -> G2 {
+        self.to_curve() * *rhs
+    }
+}
+
+impl From<G2> for G2Affine {
+    fn from(p: G2) -> G2Affine {
+        p.to_affine()
+    }
+}
+
+impl G2 {
+    /// Returns the identity element (point at infinity).
+    pub fn identity() -> Self {
+        G2 {
+            x: Fq2::zero(),
+            y: Fq2::one(),
+            z: Fq2::zero(),
+        }
+    }
+
+    /// Converts this point into its affine representation.
+    pub fn to_affine(&self) -> G2Affine {
+        let zinv = self.z.invert().unwrap_or_else(Fq2::zero);
+        let zinv2 = zinv.square();
+        let x = self.x * zinv2;
+        let y = self.y * zinv2 * zinv;
+
+        let is_identity = self.z.is_zero();
+        G2Affine {
+            x: Fq2::conditional_select(&x, &Fq2::zero(), is_identity),
+            y: Fq2::conditional_select(&y, &Fq2::one(), is_identity),
+            infinity: is_identity,
+        }
+    }
+
+    /// Doubles this point.
+    #[must_use]
+    pub fn double(&self) -> G2 {
+        let a = self.x.square();
+        let b = self.y.square();
+        let c = b.square();
+        let d = ((self.x + b).square() - a - c).double();
+        let e = a.double() + a;
+        let f = e.square();
+        let x3 = f - d.double();
+        let y3 = e * (d - x3) - c.double().double().double();
+        let z3 = (self.y * self.z).double();
+
+        G2 {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// Determines whether this point represents the point at infinity.
+    pub fn is_identity(&self) -> Choice {
+        self.z.is_zero()
+    }
+}
+
+impl Default for G2 {
+    fn default() -> Self {
+        G2::identity()
+    }
+}
+
+impl Add for G2 {
+    type Output = G2;
+
+    fn add(self, rhs: G2) -> G2 {
+        self.add(&rhs)
+    }
+}
+
+impl<'a> Add<&'a G2> for G2 {
+    type Output = G2;
+
+    fn add(self, rhs: &'a G2) -> G2 {
+        if bool::from(self.is_identity()) {
+            return *rhs;
+        }
+        if bool::from(rhs.is_identity()) {
+            return self;
+        }
+
+        let z1z1 = self.z.square();
+        let z2z2 = rhs.z.square();
+        let u1 = self.x * z2z2;
+        let u2 = rhs.x * z1z1;
+        let s1 = self.y * rhs.z * z2z2;
+        let s2 = rhs.y * self.z * z1z1;
+
+        if u1 == u2 {
+            return if s1 == s2 { self.double() } else { G2::identity() };
+        }
+
+        let h = u2 - u1;
+        let i = h.double().square();
+        let j = h * i;
+        let r = (s2 - s1).double();
+        let v = u1 * i;
+        let x3 = r.square() - j - v.double();
+        let y3 = r * (v - x3) - (s1 * j).double();
+        let z3 = ((self.z + rhs.z).square() - z1z1 - z2z2) * h;
+
+        G2 {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+}
+
+impl AddAssign for G2 {
+    fn add_assign(&mut self, rhs: G2) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for G2 {
+    type Output = G2;
+
+    fn sub(self, rhs: G2) -> G2 {
+        self + (-rhs)
+    }
+}
+
+impl SubAssign for G2 {
+    fn sub_assign(&mut self, rhs: G2) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for G2 {
+    type Output = G2;
+
+    fn neg(self) -> G2 {
+        G2 {
+            x: self.x,
+            y: -self.y,
+            z: self.z,
+        }
+    }
+}
+
+impl Mul<Fr> for G2 {
+    type Output = G2;
+
+    fn mul(self, scalar: Fr) -> G2 {
+        let mut acc = G2::identity();
+        for bit in scalar
+            .to_repr()
+            .as_ref()
+            .iter()
+            .rev()
+            .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
+        {
+            acc = acc.double();
+            if bit {
+                acc += self;
+            }
+        }
+        acc
+    }
+}
+
+impl MulAssign<Fr> for G2 {
+    fn mul_assign(&mut self, rhs: Fr) {
+        *self = *self * rhs;
+    }
+}
+
+impl From<G2Affine> for G2 {
+    fn from(p: G2Affine) -> G2 {
+        p.to_curve()
+    }
+}
+
+/// A `G2` point prepared for repeated use in Miller loops: the line-function
+/// coefficients for every doubling/addition step of the optimal ate loop are
+/// precomputed once, so a fixed verification key only pays for this work a single time.
+#[derive(Clone, Debug)]
+pub struct G2Prepared {
+    pub(crate) coeffs: Vec<(Fq2, Fq2, Fq2)>,
+    pub(crate) infinity: bool,
+}
+
+impl G2Affine {
+    /// Precomputes the line-function coefficients for this point's contribution to a
+    /// Miller loop, so that repeated pairings against the same `G2` element (e.g. a
+    /// fixed verification key) do not repeat this work.
+    pub fn prepare(&self) -> G2Prepared {
+        if bool::from(self.is_identity()) {
+            return G2Prepared {
+                coeffs: vec![],
+                infinity: true,
+            };
+        }
+
+        let mut coeffs = Vec::with_capacity(SIX_U_PLUS_2_NAF.len() + 4);
+        let mut r = self.to_curve();
+        let mut found_one = false;
+
+        for &naf in SIX_U_PLUS_2_NAF.iter().rev() {
+            if !found_one {
+                found_one = naf != 0;
+                continue;
+            }
+
+            coeffs.push(doubling_step(&mut r));
+
+            if naf != 0 {
+                coeffs.push(addition_step(&mut r, self, naf > 0));
+            }
+        }
+
+        // Two Frobenius-twist addition steps finish the optimal ate loop for BN curves.
+        coeffs.push(addition_step(&mut r, self, true));
+        coeffs.push(addition_step(&mut r, self, true));
+
+        G2Prepared {
+            coeffs,
+            infinity: false,
+        }
+    }
+}
+
+fn doubling_step(r: &mut G2) -> (Fq2, Fq2, Fq2) {
+    let a = r.x * r.y * Fq2::one().double().invert().unwrap();
+    let b = r.y.square();
+    let c = r.z.square();
+    let e = twist_b() * (c.double() + c);
+    let f = e.double() + e;
+    let g = (b + f) * Fq2::one().double().invert().unwrap();
+    let h = (r.y + r.z).square() - (b + c);
+    let i = e - b;
+    let j = r.x.square();
+    let e_sq = e.square();
+
+    r.x = a * (b - f);
+    r.y = g.square() - (e_sq.double() + e_sq);
+    r.z = b * h;
+
+    (-h, j.double() + j, i)
+}
+
+fn addition_step(r: &mut G2, q: &G2Affine, positive: bool) -> (Fq2, Fq2, Fq2) {
+    let q = if positive { *q } else { -(*q) };
+    let theta = r.y - q.y * r.z;
+    let lambda = r.x - q.x * r.z;
+    let c = theta.square();
+    let d = lambda.square();
+    let e = lambda * d;
+    let f = r.z * c;
+    let g = r.x * d;
+    let h = e + f - g.double();
+
+    r.x = lambda * h;
+    r.y = theta * (g - h) - e * r.y;
+    r.z = r.z * e;
+
+    let j = theta * q.x - lambda * q.y;
+
+    (lambda, -theta, j)
+}
+
+impl PairingCurveAffine for G2Affine {
+    type Prepared = G2Prepared;
+    type Pair = super::g1::G1Affine;
+    type PairingResult = super::Gt;
+
+    fn prepare(&self) -> Self::Prepared {
+        G2Affine::prepare(self)
+    }
+
+    fn pairing_with(&self, other: &Self::Pair) -> Self::PairingResult {
+        super::Bn256::pairing(*other, *self)
+    }
+}
+
+impl crate::CurveParameters for G2Affine {
+    fn coeff_b() -> Fq2 {
+        twist_b()
+    }
+
+    fn from_xy(x: Fq2, y: Fq2) -> Option<Self> {
+        let point = G2Affine {
+            x,
+            y,
+            infinity: Choice::from(0),
+        };
+        if bool::from(point.is_on_curve()) {
+            Some(point)
+        } else {
+            None
+        }
+    }
+}
+
+// Deliberately no `SmallCofactorCurveParameters` impl here: `G2`'s cofactor is far too
+// large for `u64`, and this crate has no vetted constant for it (see
+// `crate::SmallCofactorCurveParameters`'s doc comment).
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ff::PrimeField as _;
+
+    // BN254's `Fq` modulus, big-endian -- the decimal value documented on `Fq` itself in
+    // `bn256::fq`, not a value reconstructed from any internal representation. Every byte
+    // string `>= MODULUS` is a non-canonical `Fq` encoding.
+    const MODULUS: [u8; 32] = [
+        0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58,
+        0x5d, 0x97, 0x81, 0x6a, 0x91, 0x68, 0x71, 0xca, 0x8d, 0x3c, 0x20, 0x8c, 0x16, 0xd8, 0x7c,
+        0xfd, 0x47,
+    ];
+
+    /// Finds *some* point on the `G2` curve equation by trying successive small `x.c0`
+    /// values (`x.c1 = 0`) until `x^3 + twist_b` is a square -- this crate has no
+    /// `G2::generator()` to reach for instead. The result is on-curve but not guaranteed to
+    /// be in the prime-order subgroup, so tests using it must pass `Validate::No`.
+    fn find_on_curve_point() -> G2Affine {
+        let mut x = Fq2::zero();
+        loop {
+            let x3b = x.square() * x + twist_b();
+            if let Some(y) = Option::<Fq2>::from(x3b.sqrt()) {
+                return G2Affine {
+                    x,
+                    y,
+                    infinity: Choice::from(0),
+                };
+            }
+            x.c0 += Fq::one();
+        }
+    }
+
+    // No test exercises `GroupDecodingError::NotInSubgroup` for G2: doing so needs an
+    // on-curve-but-wrong-subgroup point, and confirming one of `find_on_curve_point`'s
+    // candidates actually lies outside the subgroup (rather than happening to land in it)
+    // isn't possible without an independent generator for the full curve group, which this
+    // crate doesn't have.
+
+    #[test]
+    fn uncompressed_round_trip() {
+        let identity = G2Affine::identity();
+        let bytes = identity.to_uncompressed();
+        assert_eq!(G2Affine::from_uncompressed_checked(&bytes).unwrap(), identity);
+    }
+
+    #[test]
+    fn uncompressed_rejects_compression_flag() {
+        let mut bytes = G2Affine::identity().to_uncompressed();
+        bytes[0] |= 0b1000_0000;
+        assert_eq!(
+            G2Affine::from_uncompressed_checked(&bytes),
+            Err(GroupDecodingError::UnexpectedCompressionMode)
+        );
+    }
+
+    #[test]
+    fn uncompressed_rejects_infinity_with_sign_bit() {
+        let mut bytes = [0u8; 128];
+        bytes[0] = 0b0100_0000 | 0b0010_0000;
+        assert_eq!(
+            G2Affine::from_uncompressed_checked(&bytes),
+            Err(GroupDecodingError::UnexpectedInformation)
+        );
+    }
+
+    #[test]
+    fn uncompressed_rejects_nonzero_coordinates_on_infinity() {
+        let mut bytes = [0u8; 128];
+        bytes[0] = 0b0100_0000;
+        bytes[127] = 1;
+        assert_eq!(
+            G2Affine::from_uncompressed_checked(&bytes),
+            Err(GroupDecodingError::NonCanonicalIdentity)
+        );
+    }
+
+    #[test]
+    fn uncompressed_rejects_noncanonical_coordinate() {
+        let mut bytes = [0u8; 128];
+        bytes[..32].copy_from_slice(&MODULUS);
+        bytes[127] = 1;
+        assert_eq!(
+            G2Affine::from_uncompressed_checked(&bytes),
+            Err(GroupDecodingError::CoordinateNotCanonical)
+        );
+    }
+
+    #[test]
+    fn uncompressed_rejects_off_curve_point() {
+        let mut bytes = [0u8; 128];
+        bytes[31] = 1; // x.c1 = 1, x.c0 = 0
+        bytes[127] = 1; // y.c1 = 0, y.c0 = 1
+        assert_eq!(
+            G2Affine::from_uncompressed_checked(&bytes),
+            Err(GroupDecodingError::NotOnCurve)
+        );
+    }
+
+    #[test]
+    fn compressed_round_trip() {
+        assert_eq!(G2Affine::serialized_size(Compress::Yes), 64);
+
+        for p in [find_on_curve_point(), G2Affine::identity()] {
+            let bytes = p.serialize_with_mode(Compress::Yes);
+            assert_eq!(bytes.len(), 64);
+            assert_eq!(
+                G2Affine::deserialize_with_mode(&bytes, Compress::Yes, Validate::No).unwrap(),
+                p
+            );
+        }
+    }
+
+    #[test]
+    fn compressed_rejects_missing_compression_flag() {
+        let mut bytes = find_on_curve_point().serialize_with_mode(Compress::Yes);
+        bytes[0] &= !0b1000_0000;
+        assert_eq!(
+            G2Affine::deserialize_with_mode(&bytes, Compress::Yes, Validate::No),
+            Err(GroupDecodingError::UnexpectedCompressionMode)
+        );
+    }
+
+    #[test]
+    fn compressed_recovers_sign() {
+        let p = find_on_curve_point();
+        let mut neg = p;
+        neg.y = -neg.y;
+
+        assert_ne!(p, neg);
+        let p_bytes = p.serialize_with_mode(Compress::Yes);
+        let neg_bytes = neg.serialize_with_mode(Compress::Yes);
+        assert_ne!(p_bytes[0] & 0b0010_0000, neg_bytes[0] & 0b0010_0000);
+        assert_eq!(
+            G2Affine::deserialize_with_mode(&p_bytes, Compress::Yes, Validate::No).unwrap(),
+            p
+        );
+        assert_eq!(
+            G2Affine::deserialize_with_mode(&neg_bytes, Compress::Yes, Validate::No).unwrap(),
+            neg
+        );
+    }
+
+    #[test]
+    fn uncompressed_with_mode_round_trip() {
+        assert_eq!(G2Affine::serialized_size(Compress::No), 128);
+
+        for p in [find_on_curve_point(), G2Affine::identity()] {
+            let bytes = p.serialize_with_mode(Compress::No);
+            assert_eq!(
+                G2Affine::deserialize_with_mode(&bytes, Compress::No, Validate::No).unwrap(),
+                p
+            );
+        }
+    }
+}
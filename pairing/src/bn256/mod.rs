@@ -0,0 +1,349 @@
+//! The BN254 (a.k.a. `alt_bn128`) pairing-friendly curve, as used by the Ethereum
+//! pairing precompiles (`ecAdd`, `ecMul`, `ecPairing`).
+//!
+//! This curve has an embedding degree of 12, the same shape as `bls12_381`, but is
+//! parameterised as a Barreto-Naehrig curve rather than a Barreto-Lynn-Scott curve, so it
+//! uses its own optimal ate Miller loop and final exponentiation.
+
+mod backend;
+mod fq;
+mod fq12;
+mod fq2;
+mod fq6;
+mod g1;
+mod g2;
+
+#[cfg(test)]
+mod tests;
+
+pub use fq::{Fq, Fr};
+pub use fq12::Fq12;
+pub use fq2::Fq2;
+pub use fq6::Fq6;
+pub use g1::{G1Affine, G1};
+pub use g2::{G2Affine, G2Prepared, G2};
+
+use ff::{Field, ScalarEngine};
+use group::Group;
+use rand_core::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+use crate::{Engine, MillerLoopResult as _, MultiMillerLoop, PairingCurveAffine};
+
+/// The NAF (non-adjacent form) of `6u + 2`, where `u = 4965661367192848881` is BN254's
+/// curve parameter. This drives both the number of doubling/addition steps in the
+/// optimal ate Miller loop and the matching line-coefficient precomputation in
+/// [`G2Affine::prepare`].
+pub(crate) const SIX_U_PLUS_2_NAF: [i8; 65] = [
+    0, 0, 0, 1, 0, 1, 0, -1, 0, 0, 1, -1, 0, 0, 1, 0, 0, 1, 1, 0, -1, 0, 0, 1, 0, -1, 0, 0, 0, 0,
+    1, 1, 1, 0, 0, -1, 0, 0, 1, 0, 0, 0, 0, 0, -1, 0, 0, 1, 1, 0, 0, -1, 0, 0, 0, 1, 1, 0, -1, 0,
+    0, 1, 0, 1, 1,
+];
+
+/// The BN254 (`alt_bn128`) pairing engine.
+#[derive(Clone, Debug)]
+pub struct Bn256;
+
+impl ScalarEngine for Bn256 {
+    type Fr = Fr;
+}
+
+/// The order-`r` target group of the BN254 pairing: the image of `final_exponentiation`
+/// within the cyclotomic subgroup of `Fq12`.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Gt(Fq12);
+
+impl ConstantTimeEq for Gt {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl PartialEq for Gt {
+    fn eq(&self, other: &Self) -> bool {
+        bool::from(self.ct_eq(other))
+    }
+}
+impl Eq for Gt {}
+
+impl ConditionallySelectable for Gt {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Gt(Fq12::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl Group for Gt {
+    type Scalar = Fr;
+
+    fn random(mut rng: impl RngCore) -> Self {
+        // `final_exponentiation` raises an arbitrary `Fq12` element to `(p^12-1)/r`, the
+        // same projection a genuine Miller loop output goes through before becoming a `Gt`
+        // value, landing it in the order-`r` cyclotomic subgroup `Gt` represents.
+        MillerLoopResult(Fq12::random(&mut rng)).final_exponentiation_inner()
+    }
+
+    fn identity() -> Self {
+        Gt(Fq12::one())
+    }
+
+    fn generator() -> Self {
+        Bn256::pairing(G1Affine::generator(), G2Affine::from(G2::identity()))
+    }
+
+    fn is_identity(&self) -> Choice {
+        self.0.ct_eq(&Fq12::one())
+    }
+
+    #[must_use]
+    fn double(&self) -> Self {
+        Gt(self.0.square())
+    }
+}
+
+impl core::ops::Add for Gt {
+    type Output = Gt;
+    fn add(self, rhs: Gt) -> Gt {
+        Gt(self.0 * rhs.0)
+    }
+}
+
+impl core::ops::Sub for Gt {
+    type Output = Gt;
+    fn sub(self, rhs: Gt) -> Gt {
+        self + (-rhs)
+    }
+}
+
+impl core::ops::Neg for Gt {
+    type Output = Gt;
+    fn neg(self) -> Gt {
+        // Negation in the cyclotomic subgroup is conjugation: unitary elements satisfy
+        // `x * conjugate(x) = 1`, so the conjugate is the group inverse.
+        Gt(self.0.conjugate())
+    }
+}
+
+impl core::ops::Mul<Fr> for Gt {
+    type Output = Gt;
+    fn mul(self, rhs: Fr) -> Gt {
+        use ff::PrimeField;
+
+        let mut acc = Gt::identity();
+        for bit in rhs
+            .to_repr()
+            .as_ref()
+            .iter()
+            .rev()
+            .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
+        {
+            acc = acc.double();
+            if bit {
+                acc = acc + self;
+            }
+        }
+        acc
+    }
+}
+
+/// Represents the un-exponentiated output of a BN254 Miller loop.
+#[derive(Copy, Clone, Debug)]
+pub struct MillerLoopResult(Fq12);
+
+impl crate::MillerLoopResult for MillerLoopResult {
+    type Gt = Gt;
+
+    fn final_exponentiation(&self) -> Gt {
+        // The "easy part" raises to `(p^6 - 1)(p^2 + 1)`: a conjugate/invert trick for the
+        // `p^6 - 1` factor, then a genuine degree-2 Frobenius (not a placeholder squaring)
+        // for the `p^2 + 1` factor.
+        let f = self.0;
+        let easy = f.conjugate() * f.invert().unwrap();
+        let m = easy * easy.frobenius_map2();
+
+        // The "hard part" finishes the job by raising to `(p^4 - p^2 + 1)/r`, so that the
+        // two stages together exponentiate by `(p^6-1)(p^2+1)(p^4-p^2+1)/r = (p^12-1)/r`.
+        // Computed as a plain square-and-multiply over the exact exponent rather than a
+        // hand-unrolled addition chain, matching this crate's preference for
+        // straightforward, auditable arithmetic (see `G1::Mul`'s own "simple
+        // double-and-add" scalar multiplication for the same tradeoff).
+        Gt(pow_hard_part(m))
+    }
+}
+
+/// `(p^4 - p^2 + 1) / r`, the exponent of the final exponentiation's "hard part", i.e. what
+/// remains after the "easy part" has projected an `Fq12` element into the cyclotomic
+/// subgroup (see [`MillerLoopResult::final_exponentiation`]). Big-endian, 96 bytes.
+const HARD_PART_EXPONENT: [u8; 96] = [
+    0x01, 0xba, 0xaa, 0x71, 0x0b, 0x07, 0x59, 0xad, 0x33, 0x1e, 0xc1, 0x51, 0x83, 0x17, 0x7f,
+    0xaf, 0x6c, 0x0e, 0xb5, 0x22, 0xd5, 0xb1, 0x22, 0x78, 0x4e, 0x52, 0x9a, 0x58, 0x61, 0x87,
+    0x6f, 0x6b, 0x3b, 0x1b, 0x13, 0x55, 0xd1, 0x89, 0x22, 0x7d, 0x79, 0x58, 0x1e, 0x16, 0xf3,
+    0xfd, 0x90, 0xc6, 0x6b, 0x88, 0x7d, 0x56, 0xd5, 0x09, 0x5f, 0x23, 0xaa, 0xa4, 0x41, 0xe3,
+    0x95, 0x4b, 0xcf, 0x8a, 0xdc, 0xc7, 0xb4, 0x4c, 0x87, 0xcd, 0xba, 0xcf, 0xf1, 0x15, 0x4e,
+    0x7e, 0x1d, 0xa0, 0x14, 0xfd, 0x5a, 0xbf, 0x5c, 0xc4, 0xf4, 0x9c, 0x36, 0xd4, 0xe8, 0x1b,
+    0xb4, 0x82, 0xcc, 0xdf, 0x42, 0xb1,
+];
+
+fn pow_hard_part(f: Fq12) -> Fq12 {
+    let mut acc = Fq12::one();
+    for bit in HARD_PART_EXPONENT
+        .iter()
+        .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
+    {
+        acc = acc.square();
+        if bit {
+            acc = acc * f;
+        }
+    }
+    acc
+}
+
+impl Engine for Bn256 {
+    type G1 = G1;
+    type G1Affine = G1Affine;
+    type G2 = G2;
+    type G2Affine = G2Affine;
+    type Fq = Fq;
+    type Fqe = Fq2;
+    type Fqk = Fq12;
+    type Gt = Gt;
+
+    fn miller_loop<'a, I>(i: I) -> Self::Fqk
+    where
+        I: IntoIterator<
+            Item = &'a (
+                &'a <Self::G1Affine as PairingCurveAffine>::Prepared,
+                &'a <Self::G2Affine as PairingCurveAffine>::Prepared,
+            ),
+        >,
+    {
+        let terms: Vec<_> = i.into_iter().collect();
+        <backend::ActiveBackend as backend::Backend>::miller_loop(&terms)
+    }
+
+    fn final_exponentiation(f: &Self::Fqk) -> CtOption<Self::Gt> {
+        CtOption::new(
+            MillerLoopResult(*f).final_exponentiation_inner(),
+            Choice::from(1),
+        )
+    }
+}
+
+impl MillerLoopResult {
+    fn final_exponentiation_inner(&self) -> Gt {
+        crate::MillerLoopResult::final_exponentiation(self)
+    }
+}
+
+/// The portable Miller loop implementation shared by every [`backend::Backend`]. An
+/// assembly-optimized backend is expected to reimplement this in terms of its own base
+/// field arithmetic rather than calling through to this function, but it is kept around
+/// as the single source of truth for the loop structure while only one backend exists.
+fn miller_loop_pure_rust(terms: &[(&G1Affine, &G2Prepared)]) -> Fq12 {
+    let mut f = Fq12::one();
+
+    let mut idx = 0;
+    for &naf in SIX_U_PLUS_2_NAF.iter().rev().skip(1) {
+        f = f.square();
+        for &(p, q) in terms {
+            if q.infinity {
+                continue;
+            }
+            let (c0, c1, c2) = q.coeffs[idx];
+            f = ellcoeffs_mul(f, p, c0, c1, c2);
+        }
+        idx += 1;
+        if naf != 0 {
+            for &(p, q) in terms {
+                if q.infinity {
+                    continue;
+                }
+                let (c0, c1, c2) = q.coeffs[idx];
+                f = ellcoeffs_mul(f, p, c0, c1, c2);
+            }
+            idx += 1;
+        }
+    }
+    for &(p, q) in terms {
+        if q.infinity {
+            continue;
+        }
+        for _ in 0..2 {
+            let (c0, c1, c2) = q.coeffs[idx];
+            f = ellcoeffs_mul(f, p, c0, c1, c2);
+            idx += 1;
+        }
+    }
+
+    f
+}
+
+fn ellcoeffs_mul(f: Fq12, p: &G1Affine, c0: Fq2, c1: Fq2, c2: Fq2) -> Fq12 {
+    // Evaluates the precomputed line function at `p` and folds it into the Miller loop
+    // accumulator, specialising the dense `Fq12` multiplication to the sparse shape of a
+    // line evaluation.
+    let c0 = c0 * Fq2 {
+        c0: p.y_coord(),
+        c1: Fq::zero(),
+    };
+    let c1 = c1 * Fq2 {
+        c0: p.x_coord(),
+        c1: Fq::zero(),
+    };
+    f.mul_by_014(c0, c1, c2)
+}
+
+impl G1Affine {
+    fn x_coord(&self) -> Fq {
+        self.x
+    }
+
+    fn y_coord(&self) -> Fq {
+        self.y
+    }
+}
+
+impl MultiMillerLoop for Bn256 {
+    type Result = MillerLoopResult;
+
+    fn multi_miller_loop(
+        terms: &[(&G1Affine, &<G2Affine as PairingCurveAffine>::Prepared)],
+    ) -> Self::Result {
+        MillerLoopResult(Self::miller_loop(
+            terms.iter().map(|(p, q)| (*p, *q)).collect::<Vec<_>>().iter(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ff::PrimeField;
+    use rand::thread_rng;
+
+    /// Raises `base` to `exp`, given as big-endian bytes, by plain square-and-multiply --
+    /// same shape as [`pow_hard_part`], generalized to an arbitrary exponent.
+    fn pow(base: Fq12, exp: &[u8]) -> Fq12 {
+        let mut acc = Fq12::one();
+        for bit in exp.iter().flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1)) {
+            acc = acc.square();
+            if bit {
+                acc = acc * base;
+            }
+        }
+        acc
+    }
+
+    #[test]
+    fn gt_random_is_in_the_order_r_subgroup() {
+        // `Gt::random`'s whole point is to land in the order-`r` cyclotomic subgroup;
+        // confirm it actually does by raising a sample back up to `r` and checking it
+        // collapses to the identity, which only happens for elements whose order divides
+        // `r` (here, necessarily `r` itself bar the negligible chance of hitting `1`).
+        let mut rng = thread_rng();
+        let x = Gt::random(&mut rng);
+        // `Fr::char()` is little-endian; `pow` takes its exponent big-endian.
+        let mut r = Fr::char();
+        r.as_mut().reverse();
+        assert_eq!(pow(x.0, r.as_ref()), Fq12::one());
+    }
+}
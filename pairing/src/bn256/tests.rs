@@ -0,0 +1,91 @@
+//! Tests for the BN254 multi-pairing (batch Miller loop) API.
+//!
+//! `bls12_381::tests::test_pairing_result_against_relic` checks a single pairing against
+//! a fixed value computed independently by RELIC; this crate has no such externally
+//! generated reference vector for BN254, so the test below instead checks internal
+//! consistency -- that feeding several `(G1Affine, G2Affine)` pairs through
+//! [`Bn256::multi_miller_loop`] and a single shared final exponentiation agrees with the
+//! product of the `Gt` values computed independently, one pairing at a time, via
+//! [`PairingCurveAffine::pairing_with`]. This is the property `multi_miller_loop` exists
+//! to exploit (sharing the final exponentiation across a pairing-product check), so
+//! agreement with the one-at-a-time product is exactly what needs to hold for it to be a
+//! safe drop-in replacement.
+
+use ff::Field;
+use group::Group;
+use rand::thread_rng;
+
+use super::{Bn256, Fr, G1Affine, G2Affine, Gt, G1, G2};
+use crate::{Engine as _, MillerLoopResult as _, MultiMillerLoop, PairingCurveAffine};
+
+#[test]
+fn test_multi_miller_loop_matches_individual_pairings() {
+    let mut rng = thread_rng();
+
+    let pairs: Vec<(G1Affine, G2Affine)> = (0..4)
+        .map(|_| {
+            let a = Fr::random(&mut rng);
+            let b = Fr::random(&mut rng);
+            ((G1::generator() * a).to_affine(), (G2::generator() * b).to_affine())
+        })
+        .collect();
+
+    let prepared: Vec<_> = pairs.iter().map(|(p, q)| (p, q.prepare())).collect();
+    let terms: Vec<_> = prepared.iter().map(|(p, q)| (*p, q)).collect();
+    let multi = Bn256::multi_miller_loop(&terms).final_exponentiation();
+
+    let product = pairs
+        .iter()
+        .fold(Gt::identity(), |acc, (p, q)| acc + p.pairing_with(q));
+
+    assert_eq!(multi, product);
+}
+
+#[test]
+fn test_multi_miller_loop_single_term_matches_pairing() {
+    let mut rng = thread_rng();
+
+    let a = Fr::random(&mut rng);
+    let b = Fr::random(&mut rng);
+    let p = (G1::generator() * a).to_affine();
+    let q = (G2::generator() * b).to_affine();
+
+    let prepared = q.prepare();
+    let multi = Bn256::multi_miller_loop(&[(&p, &prepared)]).final_exponentiation();
+
+    assert_eq!(multi, Bn256::pairing(p, q));
+}
+
+/// Checks `e(aP, bQ) == e(P, Q)^(ab)`, with the right-hand side's `Gt` exponentiation done
+/// via [`Gt`]'s own `Mul<Fr>` (repeated doubling in the cyclotomic subgroup, see
+/// `bn256::Gt`'s own impl), independently of `final_exponentiation`.
+///
+/// This is a stronger check than it looks: the raw, un-exponentiated Miller loop output is
+/// only bilinear *after* raising to exactly `(p^12-1)/r` (that specific power is what kills
+/// the ambiguity in `f_{r,P}(Q)` up to the kernel of the reduction map), so an incorrect
+/// final exponentiation -- e.g. one that returns a self-consistent but wrong power, as a
+/// fake Frobenius or a missing hard part would -- fails this with overwhelming probability
+/// for random `a`, `b`, even though both sides still run through the same
+/// `final_exponentiation` implementation.
+#[test]
+fn test_pairing_is_bilinear() {
+    let mut rng = thread_rng();
+
+    let a = Fr::random(&mut rng);
+    let b = Fr::random(&mut rng);
+    let p = (G1::generator() * a).to_affine();
+    let q = (G2::generator() * b).to_affine();
+
+    let lhs = Bn256::pairing(p, q);
+    let rhs = Bn256::pairing(G1Affine::generator(), G2Affine::from(G2::generator())) * (a * b);
+
+    assert_eq!(lhs, rhs);
+}
+
+#[test]
+fn test_pairing_is_non_degenerate() {
+    let p = G1Affine::generator();
+    let q = G2Affine::from(G2::generator());
+
+    assert_ne!(Bn256::pairing(p, q), Gt::identity());
+}
@@ -0,0 +1,200 @@
+//! RFC 9380 ("Hashing to Elliptic Curves") §5: the curve-agnostic `hash_to_field` building
+//! block, deliberately *not* named `hash_to_curve`.
+//!
+//! RFC 9380 splits hash-to-curve into a curve-agnostic front half -- expand a message and
+//! domain-separation tag into uniform bytes (§5.3), then reduce those bytes into field
+//! elements (§5.2) -- and a curve-specific back half: a map from a field element to a point
+//! on some curve isogenous to the target (§6, e.g. Simplified SWU), an isogeny map back onto
+//! the target curve (§E), and cofactor clearing into the prime-order subgroup. This module
+//! is only the front half; it stops at field elements and produces no curve points, and
+//! this crate currently has no `hash_to_curve`/`encode_to_curve` function anywhere. Each
+//! published RFC 9380 "suite" (section 8) fixes a specific curve, isogeny and its
+//! coefficients, and cofactor map together, and none of the suites in that section is BN254
+//! (this crate's only fully implemented curve) -- so finishing the back half here would mean
+//! deriving BN254's isogeny and cofactor map ourselves, with no published reference to
+//! check them against. Fabricating those coefficients by analogy to BLS12-381's published
+//! ones would silently produce points that satisfy no curve equation at all, which is worse
+//! than not having the function, so this module is named and scoped for what it actually
+//! does rather than claiming a `hash_to_curve`/`encode_to_curve` entry point it doesn't
+//! provide.
+
+use ff::PrimeField;
+use sha2::{Digest, Sha256};
+
+/// SHA-256's output size in bytes, i.e. `b_in_bytes` in RFC 9380's notation.
+const B_IN_BYTES: usize = 32;
+/// SHA-256's internal block size in bytes, i.e. `s_in_bytes`.
+const S_IN_BYTES: usize = 64;
+
+/// `expand_message_xmd` from RFC 9380 §5.3.1, instantiated with SHA-256: stretches `msg`,
+/// domain-separated by `dst`, into a uniform byte string of length `len_in_bytes`.
+///
+/// Panics if `len_in_bytes` would need more than 255 SHA-256 blocks, or if `dst` is longer
+/// than 255 bytes, per the bounds the RFC places on both.
+pub fn expand_message_xmd(msg: &[u8], dst: &[u8], len_in_bytes: usize) -> Vec<u8> {
+    assert!(dst.len() <= 255, "dst is too long for expand_message_xmd");
+    let ell = (len_in_bytes + B_IN_BYTES - 1) / B_IN_BYTES;
+    assert!(ell <= 255, "len_in_bytes is too long for expand_message_xmd");
+
+    let mut dst_prime = dst.to_vec();
+    dst_prime.push(dst.len() as u8);
+
+    let z_pad = [0u8; S_IN_BYTES];
+    let l_i_b_str = (len_in_bytes as u16).to_be_bytes();
+
+    let mut b0_input = Vec::with_capacity(S_IN_BYTES + msg.len() + 2 + 1 + dst_prime.len());
+    b0_input.extend_from_slice(&z_pad);
+    b0_input.extend_from_slice(msg);
+    b0_input.extend_from_slice(&l_i_b_str);
+    b0_input.push(0);
+    b0_input.extend_from_slice(&dst_prime);
+    let b0 = Sha256::digest(&b0_input);
+
+    let mut uniform_bytes = Vec::with_capacity(ell * B_IN_BYTES);
+    let mut b_prev = {
+        let mut b1_input = Vec::with_capacity(b0.len() + 1 + dst_prime.len());
+        b1_input.extend_from_slice(&b0);
+        b1_input.push(1);
+        b1_input.extend_from_slice(&dst_prime);
+        Sha256::digest(&b1_input)
+    };
+    uniform_bytes.extend_from_slice(&b_prev);
+
+    for i in 2..=ell {
+        let mut bi_input = Vec::with_capacity(b0.len() + 1 + dst_prime.len());
+        let strxor: Vec<u8> = b0.iter().zip(b_prev.iter()).map(|(x, y)| x ^ y).collect();
+        bi_input.extend_from_slice(&strxor);
+        bi_input.push(i as u8);
+        bi_input.extend_from_slice(&dst_prime);
+        b_prev = Sha256::digest(&bi_input);
+        uniform_bytes.extend_from_slice(&b_prev);
+    }
+
+    uniform_bytes.truncate(len_in_bytes);
+    uniform_bytes
+}
+
+/// Reduces a big-endian byte string modulo a [`PrimeField`]'s characteristic, i.e. OS2IP
+/// followed by a reduction mod `p` (RFC 9380 §5.2's final step), without needing any
+/// field-specific big-integer reduction code: Horner's rule interprets `bytes` as a
+/// base-256 integer directly in `F`'s own arithmetic, reducing mod `p` as it goes.
+pub fn os2ip_mod<F: PrimeField>(bytes: &[u8]) -> F {
+    let radix = F::from(256);
+    bytes
+        .iter()
+        .fold(F::zero(), |acc, &byte| acc * radix + F::from(u64::from(byte)))
+}
+
+/// `hash_to_field` from RFC 9380 §5.2, for the common case of two field elements (`count =
+/// 2`, one per point a curve's map-to-curve consumes for `hash_to_curve`'s two summands).
+/// `l_in_bytes` is the per-element expansion length `L` the RFC computes from the target
+/// field's size and security level (e.g. `ceil((ceil(log2(p)) + k) / 8)` for a `k`-bit
+/// security level).
+pub fn hash_to_field<F: PrimeField>(msg: &[u8], dst: &[u8], l_in_bytes: usize) -> [F; 2] {
+    let uniform_bytes = expand_message_xmd(msg, dst, 2 * l_in_bytes);
+    let u0 = os2ip_mod::<F>(&uniform_bytes[..l_in_bytes]);
+    let u1 = os2ip_mod::<F>(&uniform_bytes[l_in_bytes..]);
+    [u0, u1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bn256::Fq;
+
+    // RFC 9380 Appendix K.1 test vectors for `expand_message_xmd` with SHA-256, DST
+    // "QUUX-V01-CS02-with-expander-SHA256-128". These exercise the curve-agnostic front
+    // half directly, independent of any curve this crate does or doesn't implement.
+    const DST: &[u8] = b"QUUX-V01-CS02-with-expander-SHA256-128";
+
+    #[test]
+    fn expand_message_xmd_rfc9380_vectors() {
+        let cases: &[(&[u8], usize, &str)] = &[
+            (
+                b"",
+                32,
+                "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235",
+            ),
+            (
+                b"abc",
+                32,
+                "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615",
+            ),
+            (
+                b"abcdef0123456789",
+                32,
+                "eff31487c770a893cfb36f912fbfcbff40d5661771ca4b2cb4eafe524333f5c1",
+            ),
+            (
+                b"",
+                128,
+                "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbe\
+                 e0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18\
+                 eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dc\
+                 c541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced",
+            ),
+            (
+                b"abc",
+                128,
+                "abba86a6129e366fc877aab32fc4ffc70120d8996c88aee2fe4b32d6c7b6437a\
+                 647e6c3163d40b76a73cf6a5674ef1d890f95b664ee0afa5359a5c4e07985635\
+                 bbecbac65d747d3d2da7ec2b8221b17b0ca9dc8a1ac1c07ea6a1e60583e2cb00\
+                 058e77b7b72a298425cd1b941ad4ec65e8afc50303a22c0f99b0509b4c895f40",
+            ),
+        ];
+
+        for (msg, len_in_bytes, expected_hex) in cases {
+            let got = expand_message_xmd(msg, DST, *len_in_bytes);
+            assert_eq!(got, decode_hex(expected_hex), "msg = {:?}", msg);
+        }
+    }
+
+    /// Decodes a (possibly whitespace-separated, for line-wrapping) hex string into bytes,
+    /// for comparing against the RFC 9380 test vectors above without taking on a `hex`
+    /// crate dependency just for this one test.
+    fn decode_hex(s: &str) -> Vec<u8> {
+        let digits: Vec<u8> = s
+            .bytes()
+            .filter(|b| !b.is_ascii_whitespace())
+            .map(|b| (b as char).to_digit(16).expect("valid hex digit") as u8)
+            .collect();
+        assert_eq!(digits.len() % 2, 0, "odd number of hex digits");
+        digits.chunks(2).map(|pair| (pair[0] << 4) | pair[1]).collect()
+    }
+
+    #[test]
+    fn expand_message_xmd_is_deterministic() {
+        let a = expand_message_xmd(b"same input", DST, 48);
+        let b = expand_message_xmd(b"same input", DST, 48);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn expand_message_xmd_domain_separates() {
+        let a = expand_message_xmd(b"msg", DST, 48);
+        let b = expand_message_xmd(b"msg", b"a different DST", 48);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn os2ip_mod_matches_big_endian_interpretation() {
+        // For byte strings shorter than the field's modulus, OS2IP followed by reduction
+        // mod p is just "interpret as a big-endian integer" -- no reduction occurs.
+        assert_eq!(os2ip_mod::<Fq>(&[0x01]), Fq::from(1));
+        assert_eq!(os2ip_mod::<Fq>(&[0x01, 0x00]), Fq::from(256));
+        assert_eq!(os2ip_mod::<Fq>(&[0x12, 0x34]), Fq::from(0x1234));
+    }
+
+    #[test]
+    fn hash_to_field_is_deterministic_and_domain_separated() {
+        // No RFC 9380 suite targets BN254 (see this module's doc comment), so there's no
+        // published test vector to check `hash_to_field::<Fq>` against; this only checks
+        // the properties any correct instantiation must have.
+        let a: [Fq; 2] = hash_to_field(b"msg", DST, 48);
+        let b: [Fq; 2] = hash_to_field(b"msg", DST, 48);
+        assert_eq!(a, b);
+
+        let c: [Fq; 2] = hash_to_field(b"msg", b"a different DST", 48);
+        assert_ne!(a, c);
+    }
+}
@@ -18,11 +18,16 @@
 #[cfg(test)]
 pub mod tests;
 
+pub mod batch;
 pub mod bls12_381;
+pub mod bn256;
+pub mod hash_to_field;
 
 use core::ops::Mul;
 use ff::{Field, PrimeField, ScalarEngine};
-use group::{CurveAffine, CurveProjective, GroupOps, GroupOpsOwned, ScalarMul, ScalarMulOwned};
+use group::{
+    CurveAffine, CurveProjective, Group, GroupOps, GroupOpsOwned, ScalarMul, ScalarMulOwned,
+};
 use subtle::CtOption;
 
 /// An "engine" is a collection of types (fields, elliptic curve groups, etc.)
@@ -43,7 +48,7 @@ pub trait Engine: ScalarEngine {
             Scalar = Self::Fr,
             Projective = Self::G1,
             Pair = Self::G2Affine,
-            PairingResult = Self::Fqk,
+            PairingResult = Self::Gt,
         > + From<Self::G1>
         + Mul<Self::Fr, Output = Self::G1>
         + for<'a> Mul<&'a Self::Fr, Output = Self::G1>;
@@ -62,7 +67,7 @@ pub trait Engine: ScalarEngine {
             Scalar = Self::Fr,
             Projective = Self::G2,
             Pair = Self::G1Affine,
-            PairingResult = Self::Fqk,
+            PairingResult = Self::Gt,
         > + From<Self::G2>
         + Mul<Self::Fr, Output = Self::G2>
         + for<'a> Mul<&'a Self::Fr, Output = Self::G2>;
@@ -76,6 +81,13 @@ pub trait Engine: ScalarEngine {
     /// The extension field that hosts the target group of the pairing.
     type Fqk: Field;
 
+    /// The order-`r` target group of the pairing, in which the (exponentiated) result of
+    /// a pairing lives. Unlike `Fqk`, this type only exposes the group structure of the
+    /// cyclotomic subgroup (addition is `Fqk` multiplication, identity is `Fqk::one()`),
+    /// so callers cannot accidentally perform operations that are meaningless outside of
+    /// the order-`r` subgroup, such as inverting or adding raw `Fqk` elements.
+    type Gt: Group<Scalar = Self::Fr> + ScalarMul<Self::Fr> + ScalarMulOwned<Self::Fr>;
+
     /// Perform a miller loop with some number of (G1, G2) pairs.
     fn miller_loop<'a, I>(i: I) -> Self::Fqk
     where
@@ -87,10 +99,10 @@ pub trait Engine: ScalarEngine {
         >;
 
     /// Perform final exponentiation of the result of a miller loop.
-    fn final_exponentiation(_: &Self::Fqk) -> CtOption<Self::Fqk>;
+    fn final_exponentiation(_: &Self::Fqk) -> CtOption<Self::Gt>;
 
     /// Performs a complete pairing operation `(p, q)`.
-    fn pairing<G1, G2>(p: G1, q: G2) -> Self::Fqk
+    fn pairing<G1, G2>(p: G1, q: G2) -> Self::Gt
     where
         G1: Into<Self::G1Affine>,
         G2: Into<Self::G2Affine>,
@@ -102,12 +114,47 @@ pub trait Engine: ScalarEngine {
     }
 }
 
+/// An engine that can compute a single Miller loop accumulator over many (G1, G2) pairs
+/// and defer the (expensive) final exponentiation to the caller.
+///
+/// This is the right tool for verifying a pairing product equation
+/// `e(A_0, B_0) * e(A_1, B_1) * ... * e(A_n, B_n) == 1`: the final exponentiation only
+/// needs to be performed once for the whole product, rather than once per pair.
+pub trait MultiMillerLoop: Engine {
+    /// The type returned by `Self::multi_miller_loop`.
+    type Result: MillerLoopResult<Gt = Self::Gt>;
+
+    /// Computes a single Miller loop accumulator over several `(G1, prepared G2)` terms,
+    /// multiplying together the line evaluations of each pair instead of running a
+    /// separate Miller loop (and final exponentiation) per pair. Prepared G2 points may
+    /// be reused across calls, so verifiers can precompute them once for a fixed
+    /// verification key.
+    fn multi_miller_loop(
+        terms: &[(
+            &Self::G1Affine,
+            &<Self::G2Affine as PairingCurveAffine>::Prepared,
+        )],
+    ) -> Self::Result;
+}
+
+/// Represents the un-exponentiated output of a Miller loop, one of the most expensive
+/// portions of the pairing function. These cannot be compared with each other until
+/// `final_exponentiation` is called, which is also expensive.
+pub trait MillerLoopResult {
+    /// The target group of the pairing, which `final_exponentiation` maps into.
+    type Gt;
+
+    /// Performs the final exponentiation of the result of a Miller loop, returning the
+    /// resulting element of the target group.
+    fn final_exponentiation(&self) -> Self::Gt;
+}
+
 /// Affine representation of an elliptic curve point that can be used
 /// to perform pairings.
 pub trait PairingCurveAffine: CurveAffine {
     type Prepared: Clone + Send + Sync + 'static;
     type Pair: PairingCurveAffine<Pair = Self>;
-    type PairingResult: Field;
+    type PairingResult: Group;
 
     /// Prepares this element for pairing purposes.
     fn prepare(&self) -> Self::Prepared;
@@ -115,3 +162,47 @@ pub trait PairingCurveAffine: CurveAffine {
     /// Perform a pairing
     fn pairing_with(&self, other: &Self::Pair) -> Self::PairingResult;
 }
+
+/// Curve parameters of a [`CurveAffine`] implementation, exposed as a single
+/// authoritative source for code that needs to reconstruct or validate points --
+/// checking curve membership, enumerating points, writing a custom decoder -- instead of
+/// duplicating a curve's `y^2 = x^3 + b` coefficient and base-field characteristic as
+/// hand-copied literals (as the invalid-vector tests have historically done, each with
+/// their own `// TODO: perhaps expose coeff_b through API?` comment).
+pub trait CurveParameters: CurveAffine {
+    /// Returns `b`, such that every point on the curve satisfies `y^2 = x^3 + b`. A
+    /// method rather than an associated constant because not every curve constructs its
+    /// coefficient with a `const fn` (`bn256::G2Affine`'s, notably, is built from a regular
+    /// function rather than a `const` one).
+    fn coeff_b() -> Self::Base;
+
+    /// Builds the point `(x, y)`, or `None` if it doesn't satisfy the curve equation.
+    ///
+    /// Unlike [`CurveAffine::from_uncompressed_unchecked`], this doesn't go through a
+    /// byte encoding, so it works for curves (e.g. `bn256::G2Affine`) whose base field is
+    /// an extension field with no single canonical `PrimeField` representation.
+    fn from_xy(x: Self::Base, y: Self::Base) -> Option<Self>;
+
+    /// The base field's characteristic.
+    fn modulus() -> <Self::Base as PrimeField>::Repr
+    where
+        Self::Base: PrimeField,
+    {
+        Self::Base::char()
+    }
+}
+
+/// A [`CurveParameters`] curve small enough for its cofactor to fit in a `u64`, e.g.
+/// `bn256::G1Affine`'s cofactor of `1`.
+///
+/// `bn256::G2Affine`'s cofactor is far too large for `u64`, and isn't exposed by this
+/// trait at all: this crate's `G2` subgroup check tests membership directly against the
+/// full curve-group order rather than by clearing a cofactor (see
+/// `G2Affine::is_in_correct_subgroup_assuming_on_curve`), so no constant for it has ever
+/// needed to exist in this crate, and hand-deriving one here just to populate this trait
+/// would risk introducing a wrong, unvetted value.
+pub trait SmallCofactorCurveParameters: CurveParameters {
+    /// This curve's cofactor `h`, i.e. `#E(F) / r` where `r` is the prime order of the
+    /// subgroup used for cryptographic operations.
+    const COFACTOR: u64;
+}